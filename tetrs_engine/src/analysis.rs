@@ -0,0 +1,110 @@
+/*!
+This module handles simple analysis of a [`Board`], e.g. to evaluate placements or display stack
+statistics.
+
+All functions only consider the tiles locked onto the board, i.e. they ignore the active piece.
+*/
+
+use crate::Board;
+
+/// Returns the height of each column of the board from left to right, i.e. one above its highest filled cell.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use tetrs_engine::{analysis, GameConfig};
+///
+/// let mut board = GameConfig::default().empty_board();
+/// board[0][0] = Some(NonZeroU32::MIN);
+/// // An overhang: column 1 is filled at row 2 but empty below.
+/// board[2][1] = Some(NonZeroU32::MIN);
+///
+/// assert_eq!(analysis::column_heights(&board), [1, 3, 0, 0, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn column_heights(board: &Board) -> Vec<usize> {
+    let width = board.first().map_or(0, |line| line.len());
+    (0..width)
+        .map(|x| {
+            board
+                .iter()
+                .rposition(|line| line[x].is_some())
+                .map_or(0, |y| y + 1)
+        })
+        .collect()
+}
+
+/// Returns the number of empty cells that have a filled cell somewhere above them.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use tetrs_engine::{analysis, GameConfig};
+///
+/// let mut board = GameConfig::default().empty_board();
+/// assert_eq!(analysis::count_holes(&board), 0);
+/// // An overhang covering two empty cells.
+/// board[2][1] = Some(NonZeroU32::MIN);
+/// // A filled column without holes.
+/// board[0][5] = Some(NonZeroU32::MIN);
+/// board[1][5] = Some(NonZeroU32::MIN);
+///
+/// assert_eq!(analysis::count_holes(&board), 2);
+/// ```
+pub fn count_holes(board: &Board) -> usize {
+    column_heights(board)
+        .iter()
+        .enumerate()
+        .map(|(x, &height)| {
+            board[..height]
+                .iter()
+                .filter(|line| line[x].is_none())
+                .count()
+        })
+        .sum()
+}
+
+/// Returns the sum of height differences between neighbouring columns of the board.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use tetrs_engine::{analysis, GameConfig};
+///
+/// let mut board = GameConfig::default().empty_board();
+/// assert_eq!(analysis::aggregate_bumpiness(&board), 0);
+/// board[2][1] = Some(NonZeroU32::MIN);
+///
+/// // Column 1 has height 3 and both of its neighbours are empty.
+/// assert_eq!(analysis::aggregate_bumpiness(&board), 6);
+/// ```
+pub fn aggregate_bumpiness(board: &Board) -> usize {
+    column_heights(board)
+        .windows(2)
+        .map(|heights| heights[0].abs_diff(heights[1]))
+        .sum()
+}
+
+/// Returns the signed height difference from each column to the next one on its right.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use tetrs_engine::{analysis, GameConfig};
+///
+/// let mut board = GameConfig::default().empty_board();
+/// board[0][0] = Some(NonZeroU32::MIN);
+/// board[2][1] = Some(NonZeroU32::MIN);
+///
+/// assert_eq!(analysis::surface_profile(&board), [2, -3, 0, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn surface_profile(board: &Board) -> Vec<isize> {
+    // NOTE: Heights are bounded by the board height, so these conversions cannot fail.
+    column_heights(board)
+        .windows(2)
+        .map(|heights| isize::try_from(heights[1]).unwrap() - isize::try_from(heights[0]).unwrap())
+        .collect()
+}
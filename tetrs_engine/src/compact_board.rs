@@ -0,0 +1,99 @@
+/*!
+This module handles a compact, human-readable (de)serialization of a [`Board`].
+
+It is meant to be used as `#[serde(with = "tetrs_engine::compact_board")]` on fields of type
+[`Board`].
+
+A board is represented as a list of strings, one per row, from the topmost non-empty row down to
+row `0`. Empty rows above the stack are omitted. Each cell of a row is represented as follows:
+- `.` for an empty cell,
+- `O`, `I`, `S`, `Z`, `T`, `L`, `J` for the standard [`TileTypeID`] of each [`Tetromino`],
+- `{N}` for any other [`TileTypeID`] `N`.
+
+For example, an `I` piece lying flat next to some unusual tile in the bottom row becomes
+`["IIII.{254}...."]`.
+*/
+
+use std::num::NonZeroU32;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Board, Line, Tetromino, TileTypeID};
+
+/// Serializes a [`Board`] into its compact representation.
+pub fn serialize<S: Serializer>(board: &Board, serializer: S) -> Result<S::Ok, S::Error> {
+    let stack_height = board
+        .iter()
+        .rposition(|line| line.iter().any(|cell| cell.is_some()))
+        .map_or(0, |y| y + 1);
+    board[..stack_height]
+        .iter()
+        .rev()
+        .map(encode_line)
+        .collect::<Vec<_>>()
+        .serialize(serializer)
+}
+
+/// Deserializes a [`Board`] from its compact representation.
+///
+/// All rows must have the same width. Since empty rows above the stack are not stored, the board
+/// only reaches up to its topmost non-empty row and needs to be padded with empty rows to the
+/// intended height, e.g. [`GameConfig::height`](crate::GameConfig::height).
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+    let rows = Vec::<String>::deserialize(deserializer)?;
+    let board = rows
+        .iter()
+        .rev()
+        .map(|row| decode_line(row).map_err(de::Error::custom))
+        .collect::<Result<Board, _>>()?;
+    let Some(width) = board.first().map(|line| line.len()) else {
+        return Ok(board);
+    };
+    if let Some(line) = board.iter().find(|line| line.len() != width) {
+        return Err(de::Error::custom(format!(
+            "row {:?} has {} cells instead of {width}",
+            encode_line(line),
+            line.len()
+        )));
+    }
+    Ok(board)
+}
+
+fn encode_line(line: &Line) -> String {
+    line.iter()
+        .map(|cell| match cell {
+            None => ".".to_string(),
+            Some(tile) => match Tetromino::from_tiletypeid(*tile) {
+                Some(tetromino) => tetromino.name_char().to_string(),
+                None => format!("{{{tile}}}"),
+            },
+        })
+        .collect()
+}
+
+fn decode_line(row: &str) -> Result<Line, String> {
+    let mut line = Vec::new();
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        let cell: Option<TileTypeID> = match c {
+            '.' => None,
+            'O' => Some(Tetromino::O.tiletypeid()),
+            'I' => Some(Tetromino::I.tiletypeid()),
+            'S' => Some(Tetromino::S.tiletypeid()),
+            'Z' => Some(Tetromino::Z.tiletypeid()),
+            'T' => Some(Tetromino::T.tiletypeid()),
+            'L' => Some(Tetromino::L.tiletypeid()),
+            'J' => Some(Tetromino::J.tiletypeid()),
+            '{' => {
+                let id = chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+                Some(
+                    id.parse::<NonZeroU32>()
+                        .map_err(|e| format!("invalid tile id {id:?} in row {row:?}: {e}"))?,
+                )
+            }
+            c => return Err(format!("unexpected character {c:?} in row {row:?}")),
+        };
+        line.push(cell);
+    }
+    Ok(line.into_boxed_slice())
+}
@@ -7,12 +7,13 @@ mechanics.
 # Examples
 
 ```
+use std::time::Duration;
 use tetrs_engine::*;
 
 // Starting a game.
-let game = Game::new(Gamemode::marathon());
+let mut game = Game::new(GameMode::marathon());
 
-let button_state_1 = ButtonsPressed::default();
+let mut button_state_1 = ButtonsPressed::default();
 button_state_1[Button::MoveLeft] = true;
 
 let update_time_1 = Duration::from_secs(3);
@@ -39,11 +40,16 @@ TODO:
 
 #![warn(missing_docs)]
 
+pub mod analysis;
+#[cfg(feature = "serde")]
+pub mod compact_board;
 pub mod piece_generation;
 pub mod piece_rotation;
+pub mod replay;
+pub mod rng;
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashSet, VecDeque},
     fmt,
     num::NonZeroU32,
     ops,
@@ -51,15 +57,15 @@ use std::{
 };
 
 use piece_generation::TetrominoGenerator;
-use piece_rotation::RotationSystem;
-use rand::rngs::ThreadRng;
+use piece_rotation::{KickInfo, RotationSystem};
+use rng::{GameRng, RngSource};
 
 /// A mapping for which buttons are pressed, usable through `impl Index<Button> for [T; 8]`.
 pub type ButtonsPressed = [bool; 8];
 /// Abstract identifier for which type of tile occupies a cell in the grid.
 pub type TileTypeID = NonZeroU32;
-/// The type of horizontal lines of the playing grid.
-pub type Line = [Option<TileTypeID>; Game::WIDTH];
+/// The type of horizontal lines of the playing grid, each [`GameConfig::width`] cells long.
+pub type Line = Box<[Option<TileTypeID>]>;
 // NOTE: Would've liked to use `impl Game { type Board = ...` (https://github.com/rust-lang/rust/issues/8995)
 /// The type of the entire two-dimensional playing grid.
 pub type Board = Vec<Line>;
@@ -75,7 +81,10 @@ pub type FeedbackEvents = Vec<(GameTime, Feedback)>;
 pub type FnGameMod = Box<
     dyn FnMut(&mut GameConfig, &mut GameMode, &mut GameState, &mut FeedbackEvents, &ModifierPoint),
 >;
-type EventMap = HashMap<InternalEvent, GameTime>;
+/// Type of functions that can be used to observe a game without modifying it, c.f.
+/// [`Game::add_observer`].
+pub type FnGameObserver =
+    Box<dyn FnMut(&GameConfig, &GameMode, &GameState, &[(GameTime, Feedback)], &ModifierPoint)>;
 
 /// Represents an abstract game input.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
@@ -159,19 +168,54 @@ pub struct ActivePiece {
 }
 
 /// Locking details stored about an active piece in play.
+///
+/// These can be used by frontends to visualize the lock delay (c.f. [`Game::lock_meter`]) and
+/// the limit on the total time a piece may rest on the ground ([`GameConfig::ground_time_max`]).
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LockingData {
     /// Whether the main piece currently touches a surface below.
-    touches_ground: bool,
+    pub touches_ground: bool,
     /// The last time the main piece was recorded to touching ground after not having done previously.
-    last_touchdown: Option<GameTime>,
+    ///
+    /// This is always `Some` while [`LockingData::touches_ground`] is set. A piece briefly lifted
+    /// off and landing again within two drop delays keeps its previous touchdown time.
+    pub last_touchdown: Option<GameTime>,
     /// The last time the main piece was recorded to be afloat after not having been previously.
-    last_liftoff: Option<GameTime>,
+    ///
+    /// This is always `Some` while [`LockingData::touches_ground`] is unset.
+    pub last_liftoff: Option<GameTime>,
     /// The total duration the main piece is allowed to touch ground until it should immediately lock down.
-    ground_time_left: Duration,
+    ///
+    /// This is the ground time left as of [`LockingData::last_touchdown`]; while the piece rests on
+    /// the ground it keeps running out from then on, c.f. [`LockingData::time_until_forced_lock`].
+    /// It is refilled to [`GameConfig::ground_time_max`] whenever the piece reaches a new lowest
+    /// position.
+    pub ground_time_left: Duration,
     /// The lowest recorded vertical position of the main piece.
-    lowest_y: usize,
+    pub lowest_y: usize,
+    /// The kick used by the most recent rotation, if the piece was not moved since.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub last_kick: Option<KickInfo>,
+    /// How many times the lock timer was refreshed by moving or rotating since the piece reached
+    /// `lowest_y`, c.f. [`GameConfig::lock_delay_max_resets`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lock_resets: u32,
+}
+
+impl LockingData {
+    /// Returns how long the piece may still rest on the ground at time `now` before it is forced
+    /// to lock, regardless of any lock delay refreshes.
+    ///
+    /// Returns `None` if the piece does not touch the ground, as ground time only runs out while it
+    /// does.
+    pub fn time_until_forced_lock(&self, now: GameTime) -> Option<Duration> {
+        if !self.touches_ground {
+            return None;
+        }
+        let ground_time = now.saturating_sub(self.last_touchdown?);
+        Some(self.ground_time_left.saturating_sub(ground_time))
+    }
 }
 
 /// Stores the ways in which a round of the game should be limited.
@@ -185,6 +229,11 @@ pub struct LockingData {
 pub struct Limits {
     /// The total time a round may be played.
     pub time: Option<(bool, Duration)>,
+    /// Whether line clears extend the time limit, by [`ScoreEvent::default_time_bonus`] each.
+    ///
+    /// The time gained so far is tracked in [`GameState::time_bonus`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub time_bonus: bool,
     /// The total number of pieces locked that may be played.
     pub pieces: Option<(bool, u32)>,
     /// The total number of full lines that may be cleared.
@@ -193,6 +242,114 @@ pub struct Limits {
     pub level: Option<(bool, NonZeroU32)>,
     /// The number of game points to earn.
     pub score: Option<(bool, u32)>,
+    /// The number of perfect clears to achieve.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub perfect_clears: Option<(bool, u32)>,
+}
+
+impl Limits {
+    /// Describes how far the given state has progressed towards each active limit.
+    ///
+    /// The descriptions are ordered as the fields of `Limits` (time, pieces, lines, level, score,
+    /// perfect clears).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{Game, GameMode, Limits};
+    ///
+    /// let game = Game::new(GameMode::zen());
+    /// let limits = Limits {
+    ///     lines: Some((true, 40)),
+    ///     time: Some((false, Duration::from_secs(120))),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     limits.describe_progress(game.state()),
+    ///     ["0min 0.00sec / 2min 0.00sec", "0/40 lns"]
+    /// );
+    /// ```
+    pub fn describe_progress(&self, state: &GameState) -> Vec<String> {
+        let format_duration = |dur: Duration| {
+            format!(
+                "{}min {}.{:02}sec",
+                dur.as_secs() / 60,
+                dur.as_secs() % 60,
+                dur.as_millis() % 1000 / 10
+            )
+        };
+        [
+            self.time.map(|(_, max_dur)| {
+                format!(
+                    "{} / {}",
+                    format_duration(state.time),
+                    format_duration(max_dur + state.time_bonus)
+                )
+            }),
+            self.pieces.map(|(_, max_pcs)| {
+                format!(
+                    "{}/{} pcs",
+                    state.pieces_played.iter().sum::<u32>(),
+                    max_pcs
+                )
+            }),
+            self.lines
+                .map(|(_, max_lns)| format!("{}/{} lns", state.lines_cleared, max_lns)),
+            self.level
+                .map(|(_, max_lvl)| format!("{}/{} lvl", state.level, max_lvl)),
+            self.score
+                .map(|(_, max_pts)| format!("{}/{} pts", state.score, max_pts)),
+            self.perfect_clears
+                .map(|(_, max_pcs)| format!("{}/{} PCs", state.perfect_clears, max_pcs)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// A statistic of a round of play that a game mode may aim to optimize.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Objective {
+    /// The time taken to reach the goal, to be minimized.
+    Time,
+    /// The number of pieces played, to be maximized.
+    Pieces,
+    /// The number of lines cleared, to be maximized.
+    Lines,
+    /// The level reached, to be maximized.
+    Level,
+    /// The number of points scored, to be maximized.
+    Score,
+    /// The number of pieces placed with more inputs than necessary, to be minimized.
+    FinesseFaults,
+}
+
+impl Objective {
+    /// Compares the final states of two rounds of play with respect to this statistic, where a
+    /// better round is ordered first.
+    ///
+    /// Time is only compared between rounds with the same progress (lines cleared).
+    pub fn compare(&self, state1: &GameState, state2: &GameState) -> std::cmp::Ordering {
+        match self {
+            Objective::Time => state1
+                .lines_cleared
+                .cmp(&state2.lines_cleared)
+                .reverse()
+                .then_with(|| state1.time.cmp(&state2.time)),
+            Objective::Pieces => {
+                let pieces1 = state1.pieces_played.iter().sum::<u32>();
+                let pieces2 = state2.pieces_played.iter().sum::<u32>();
+                pieces1.cmp(&pieces2).reverse()
+            }
+            Objective::Lines => state1.lines_cleared.cmp(&state2.lines_cleared).reverse(),
+            Objective::Level => state1.level.cmp(&state2.level).reverse(),
+            Objective::Score => state1.score.cmp(&state2.score).reverse(),
+            Objective::FinesseFaults => state1.finesse_faults.cmp(&state2.finesse_faults),
+        }
+    }
 }
 
 /// The playing configuration specific to the single, current round of play.
@@ -210,12 +367,109 @@ pub struct GameMode {
     pub increment_level: bool,
     /// The limitations under which a game may end (un)successfully.
     pub limits: Limits,
+    /// The statistic that players of this mode conventionally try to optimize, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub objective: Option<Objective>,
+    /// Configuration options that this mode is meant to be played with, if any.
+    ///
+    /// Frontends may apply these on top of the user's [`GameConfig`] when starting the mode.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recommended_config: Option<GameConfigPatch>,
+}
+
+/// A subset of [`GameConfig`] options, each of which may be overridden.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfigPatch {
+    /// Overrides [`GameConfig::delayed_auto_shift`].
+    pub delayed_auto_shift: Option<Duration>,
+    /// Overrides [`GameConfig::auto_repeat_rate`].
+    pub auto_repeat_rate: Option<Duration>,
+    /// Overrides [`GameConfig::hard_drop_delay`].
+    pub hard_drop_delay: Option<Duration>,
+    /// Overrides [`GameConfig::ground_time_max`].
+    pub ground_time_max: Option<Duration>,
+    /// Overrides [`GameConfig::line_clear_delay`].
+    pub line_clear_delay: Option<Duration>,
+    /// Overrides [`GameConfig::appearance_delay`].
+    pub appearance_delay: Option<Duration>,
+    /// Overrides [`GameConfig::twenty_g`].
+    pub twenty_g: Option<TwentyG>,
+}
+
+impl GameConfigPatch {
+    /// Overrides the fields of the given config for which this patch provides a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{GameConfig, GameConfigPatch};
+    ///
+    /// let mut config = GameConfig::default();
+    /// let patch = GameConfigPatch {
+    ///     delayed_auto_shift: Some(Duration::from_millis(100)),
+    ///     ..Default::default()
+    /// };
+    /// patch.apply(&mut config);
+    /// assert_eq!(config.delayed_auto_shift, Duration::from_millis(100));
+    /// assert_eq!(config.auto_repeat_rate, GameConfig::default().auto_repeat_rate);
+    /// ```
+    pub fn apply(&self, config: &mut GameConfig) {
+        let Self {
+            delayed_auto_shift,
+            auto_repeat_rate,
+            hard_drop_delay,
+            ground_time_max,
+            line_clear_delay,
+            appearance_delay,
+            twenty_g,
+        } = *self;
+        if let Some(delayed_auto_shift) = delayed_auto_shift {
+            config.delayed_auto_shift = delayed_auto_shift;
+        }
+        if let Some(auto_repeat_rate) = auto_repeat_rate {
+            config.auto_repeat_rate = auto_repeat_rate;
+        }
+        if let Some(hard_drop_delay) = hard_drop_delay {
+            config.hard_drop_delay = hard_drop_delay;
+        }
+        if let Some(ground_time_max) = ground_time_max {
+            config.ground_time_max = ground_time_max;
+        }
+        if let Some(line_clear_delay) = line_clear_delay {
+            config.line_clear_delay = line_clear_delay;
+        }
+        if let Some(appearance_delay) = appearance_delay {
+            config.appearance_delay = appearance_delay;
+        }
+        if let Some(twenty_g) = twenty_g {
+            config.twenty_g = twenty_g;
+        }
+    }
 }
 
 /// User-focused configuration options that mainly influence time-sensitive or cosmetic mechanics.
+///
+/// Options missing from deserialized configurations take their [`GameConfig::default`] value.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GameConfig {
+    /// The width of the board.
+    ///
+    /// The board dimensions are fixed once a game has started.
+    pub width: usize,
+    /// The total height of the board, including the buffer zone above the skyline.
+    ///
+    /// A piece locking down with any tile at or above this height is a [`GameOver::LockOut`].
+    pub height: usize,
+    /// The height of the (conventionally visible) playing grid that can be played in.
+    ///
+    /// Rows `0..skyline` make up the visible playing grid, while rows `skyline..height` make up
+    /// the 'buffer zone' in which pieces spawn and which is conventionally not rendered.
+    /// A fixed [`GameConfig::spawn_row`] may need to be adjusted along with this.
+    pub skyline: usize,
     /// The method of tetromino rotation used.
     pub rotation_system: RotationSystem,
     /// The method (and internal state) of tetromino generation used.
@@ -235,12 +489,244 @@ pub struct GameConfig {
     /// How long each spawned active piece may touch the ground in total until it should lock down
     /// immediately.
     pub ground_time_max: Duration,
+    /// How many times moving or rotating a piece on the ground may refresh its lock delay, if
+    /// limited.
+    ///
+    /// The count starts over whenever the piece falls lower than it has been before.
+    pub lock_delay_max_resets: Option<u32>,
     /// How long the game should wait after clearing a line.
     pub line_clear_delay: Duration,
     /// How long the game should wait *additionally* before spawning a new piece.
     pub appearance_delay: Duration,
     /// Whether to disable a 'soft drop' button press to explicitly and immediately lock down a piece.
     pub no_soft_drop_lock: bool,
+    /// Whether dropping pieces earns points for each cell traveled.
+    ///
+    /// Soft drops (including sonic drops and falling while soft drop is held) earn `1` point per
+    /// cell, hard drops earn `2` points per cell.
+    pub drop_scoring: bool,
+    /// At which height new pieces are spawned onto the board.
+    pub spawn_row: SpawnRow,
+    /// How many rows above its spawn position a piece may be moved up if the spawn position is
+    /// blocked, before the game ends with a [`GameOver::BlockOut`].
+    pub spawn_retry_rows: usize,
+    /// How holding the 'soft drop' button affects the active piece.
+    pub soft_drop_mode: SoftDropMode,
+    /// Whether a piece should lock down immediately once it lands by falling, with no lock delay.
+    pub instant_lock: bool,
+    /// The source of randomness used, e.g. for piece generation.
+    pub rng_source: RngSource,
+    /// How long to count down before the first piece spawns ('ready delay').
+    ///
+    /// During the countdown a [`Feedback::Countdown`] is emitted every second and no piece is in
+    /// play. The countdown is part of the game time ([`GameState::time`]), so time limits (e.g. of
+    /// [`GameMode::ultra`]) already run during it.
+    pub start_countdown: Duration,
+    /// What happens when a piece blocks out or locks out.
+    pub topout_policy: TopoutPolicy,
+    /// Which pieces locking down above the skyline count as a [`GameOver::LockOut`].
+    pub topout_rule: TopoutRule,
+    /// Whether to emit [`Feedback::BoardChanged`] whenever the board is modified.
+    pub emit_board_changes: bool,
+    /// Whether rotation buttons held while a piece spawns rotate it immediately ('IRS').
+    pub initial_rotation: bool,
+    /// At which levels pieces fall instantly ('20G') instead of following the gradual gravity curve.
+    pub twenty_g: TwentyG,
+    /// How long a piece takes to fall by one row at a given level, unless [`GameConfig::twenty_g`]
+    /// makes it fall instantly.
+    pub gravity_curve: GravityCurve,
+    /// How long a piece may rest on the ground before locking at a given level, c.f.
+    /// [`GameConfig::ground_time_max`] for the limit across lock delay refreshes.
+    ///
+    /// This is not serialized; deserialized configurations use [`Game::guideline_lock_delay`].
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_lock_delay_curve"))]
+    pub lock_delay_curve: LockDelayFn,
+    /// The maximum amount of game time a single call to [`Game::update`] may advance, if any.
+    pub max_catchup: Option<Duration>,
+    /// Whether a 'move' button held while a new piece spawns keeps its charge ('DAS carryover').
+    ///
+    /// If `true` and auto-shifting was already charged (c.f. [`GameState::das_charged`]), the new
+    /// piece immediately starts shifting at [`GameConfig::auto_repeat_rate`].
+    /// Otherwise it moves once and then waits for [`GameConfig::delayed_auto_shift`] as if the
+    /// button had just been pressed.
+    pub das_carryover: bool,
+    /// Whether frontends should show where the active piece would land ('ghost piece').
+    ///
+    /// The engine itself does not use this.
+    pub show_ghost: bool,
+    /// Which pieces keep the combo counter ([`GameState::consecutive_line_clears`]) alive.
+    pub combo_rule: ComboRule,
+    /// How the O-piece reacts to rotation under [`RotationSystem::Ocular`].
+    ///
+    /// The O-piece is never moved by rotation under the other rotation systems.
+    pub o_rotation: ORotation,
+    /// How the remaining tiles settle after lines are cleared.
+    pub line_clear_gravity: LineClearGravity,
+    /// How many points a line clear is worth.
+    ///
+    /// This is not serialized; deserialized configurations use [`ScoreEvent::default_score`].
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_scoring"))]
+    pub scoring: ScoreFn,
+}
+
+/// Computes the score bonus awarded for a line clear, c.f. [`GameConfig::scoring`].
+pub type ScoreFn = fn(&ScoreEvent) -> u32;
+
+#[cfg(feature = "serde")]
+fn default_scoring() -> ScoreFn {
+    ScoreEvent::default_score
+}
+
+/// Computes the drop delay at a given level, c.f. [`GravityCurve::Custom`].
+pub type GravityFn = fn(NonZeroU32) -> Duration;
+
+/// Represents how long a piece takes to fall by one row at each level, c.f.
+/// [`GameConfig::gravity_curve`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GravityCurve {
+    /// The standard gravity curve, c.f. [`Game::guideline_drop_delay`].
+    Guideline,
+    /// A gravity curve in the style of 'TGM', c.f. [`Game::tgm_drop_delay`].
+    Tgm,
+    /// The drop delays for levels 1, 2, 3 and so on; levels past the end of the table keep its last
+    /// entry, and an empty table behaves like [`GravityCurve::Guideline`].
+    Table(Vec<Duration>),
+    /// An arbitrary function of the level.
+    ///
+    /// This cannot be serialized, so configurations using it cannot be saved or replayed from a
+    /// file.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(GravityFn),
+}
+
+impl GravityCurve {
+    /// How long a piece takes to fall by one row at the given level according to this curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{num::NonZeroU32, time::Duration};
+    /// use tetrs_engine::GravityCurve;
+    ///
+    /// let curve = GravityCurve::Table(vec![Duration::from_millis(800), Duration::from_millis(500)]);
+    /// let level = NonZeroU32::new(5).unwrap();
+    /// assert_eq!(curve.drop_delay(level), Duration::from_millis(500));
+    /// ```
+    pub fn drop_delay(&self, level: NonZeroU32) -> Duration {
+        match self {
+            GravityCurve::Guideline => Game::guideline_drop_delay(level),
+            GravityCurve::Tgm => Game::tgm_drop_delay(level),
+            GravityCurve::Table(drop_delays) => usize::try_from(level.get() - 1)
+                .ok()
+                .and_then(|idx| drop_delays.get(idx))
+                .or(drop_delays.last())
+                .copied()
+                .unwrap_or_else(|| Game::guideline_drop_delay(level)),
+            GravityCurve::Custom(gravity_fn) => gravity_fn(level),
+        }
+    }
+}
+
+/// Computes the lock delay at a given level, c.f. [`GameConfig::lock_delay_curve`].
+pub type LockDelayFn = fn(NonZeroU32) -> Duration;
+
+#[cfg(feature = "serde")]
+fn default_lock_delay_curve() -> LockDelayFn {
+    Game::guideline_lock_delay
+}
+
+/// Represents how a held 'soft drop' button behaves.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SoftDropMode {
+    /// The piece drops by one on press, afterwards falling faster by [`GameConfig::soft_drop_factor`]
+    /// while held.
+    Continuous,
+    /// The piece drops by exactly one on press, afterwards falling at normal speed until the button
+    /// is released and pressed again.
+    StepPerPress,
+}
+
+/// Represents at which height a newly spawned piece is placed.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpawnRow {
+    /// Spawn pieces with their bottom-left bounding box corner at [`GameConfig::skyline`].
+    Skyline,
+    /// Always spawn pieces with their bottom-left bounding box corner at the given row.
+    Fixed(usize),
+    /// Spawn pieces at [`GameConfig::skyline`], or higher up in the buffer zone if the stack is in the way.
+    AboveStack,
+}
+
+/// Represents when pieces fall instantly ('20G'), i.e. drop all the way down as soon as possible.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwentyG {
+    /// Pieces fall instantly from the given level onwards.
+    Auto(NonZeroU32),
+    /// Pieces always fall instantly, regardless of level.
+    Always,
+    /// Pieces never fall instantly; higher levels keep the fastest gradual falling speed.
+    Never,
+}
+
+/// Represents which locked pieces keep an ongoing combo alive.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComboRule {
+    /// Only pieces which clear lines continue the combo; any other piece resets it.
+    LineClearsOnly,
+    /// Spins which clear no lines also keep the combo (without incrementing it).
+    KeepOnSpin,
+}
+
+/// Represents what happens when the (rotationally symmetric) O-piece is rotated.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ORotation {
+    /// Rotation leaves the piece completely unchanged (and thus does not refresh its lock timer).
+    NoOp,
+    /// Rotation shifts the piece by one column in the direction of rotation, if possible.
+    Shift,
+}
+
+/// Represents how the remaining tiles settle after lines are cleared.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineClearGravity {
+    /// Lines above a cleared line shift down by one, leaving any overhangs floating.
+    Naive,
+    /// After lines are cleared, every group of connected tiles falls until it is supported.
+    ///
+    /// If this completes further lines they are cleared in turn (each emitting its own
+    /// [`Feedback::LineClears`] and waiting for [`GameConfig::line_clear_delay`]), until the board
+    /// settles. Lines cleared by such a cascade count towards [`GameState::lines_cleared`] but award
+    /// no score.
+    Cascade,
+}
+
+/// Represents when a piece locking down above the [`GameConfig::skyline`] is a lock-out.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TopoutRule {
+    /// Only a piece locking down entirely at or above the skyline locks out.
+    AllAboveSkyline,
+    /// Any piece locking down with at least one tile at or above the skyline locks out.
+    AnyAboveSkyline,
+}
+
+/// Represents how the game reacts to a block-out or lock-out.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TopoutPolicy {
+    /// End the game with the corresponding [`GameOver`].
+    End,
+    /// Remove the given number of topmost non-empty rows from the stack and continue.
+    ///
+    /// If this does not make room for the piece, the game ends anyway.
+    ClearTopRows(usize),
 }
 
 /// An event that is scheduled by the game engine to execute some action.
@@ -271,14 +757,192 @@ pub enum InternalEvent {
     Rotate(i32),
     /// Event of attempted piece lock down.
     LockTimer,
+    /// Event of the countdown before the first piece spawns ticking.
+    Countdown,
+}
+
+/// Storage of upcoming [`InternalEvent`]s, holding at most one scheduled time per kind of event.
+///
+/// Events are stored in a fixed slot per variant of [`InternalEvent`] (the number of turns of a
+/// pending [`InternalEvent::Rotate`] is stored separately).
+/// Events scheduled at the same time are ordered by their declaration order in [`InternalEvent`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tetrs_engine::{EventMap, InternalEvent};
+///
+/// let mut events = EventMap::default();
+/// events.insert(InternalEvent::Fall, Duration::from_millis(10));
+/// events.insert(InternalEvent::Rotate(-1), Duration::from_millis(10));
+/// events.insert(InternalEvent::Lock, Duration::from_millis(20));
+/// assert_eq!(events.next_event(), Some((InternalEvent::Fall, Duration::from_millis(10))));
+/// events.remove(&InternalEvent::Fall);
+/// assert_eq!(events.next_event(), Some((InternalEvent::Rotate(-1), Duration::from_millis(10))));
+/// ```
+#[derive(Eq, PartialEq, Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EventMap {
+    times: [Option<GameTime>; InternalEvent::VARIANT_COUNT],
+    rotate_turns: i32,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EventMap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Earlier versions stored events as a plain map from event to time.
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Slots {
+                times: [Option<GameTime>; InternalEvent::VARIANT_COUNT],
+                rotate_turns: i32,
+            },
+            Map(std::collections::HashMap<InternalEvent, GameTime>),
+        }
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Slots {
+                times,
+                rotate_turns,
+            } => EventMap {
+                times,
+                rotate_turns,
+            },
+            Repr::Map(map) => {
+                let mut events = EventMap::default();
+                for (event, event_time) in map {
+                    events.insert(event, event_time);
+                }
+                events
+            }
+        })
+    }
+}
+
+impl InternalEvent {
+    const VARIANT_COUNT: usize = 12;
+
+    /// The index of the slot in an [`EventMap`] this event is stored at.
+    const fn slot(&self) -> usize {
+        match self {
+            InternalEvent::LineClear => 0,
+            InternalEvent::Spawn => 1,
+            InternalEvent::Lock => 2,
+            InternalEvent::HardDrop => 3,
+            InternalEvent::SonicDrop => 4,
+            InternalEvent::SoftDrop => 5,
+            InternalEvent::Fall => 6,
+            InternalEvent::MoveSlow => 7,
+            InternalEvent::MoveFast => 8,
+            InternalEvent::Rotate(_) => 9,
+            InternalEvent::LockTimer => 10,
+            InternalEvent::Countdown => 11,
+        }
+    }
+
+    /// The event stored at a given slot in an [`EventMap`].
+    const fn from_slot(slot: usize, rotate_turns: i32) -> Self {
+        match slot {
+            0 => InternalEvent::LineClear,
+            1 => InternalEvent::Spawn,
+            2 => InternalEvent::Lock,
+            3 => InternalEvent::HardDrop,
+            4 => InternalEvent::SonicDrop,
+            5 => InternalEvent::SoftDrop,
+            6 => InternalEvent::Fall,
+            7 => InternalEvent::MoveSlow,
+            8 => InternalEvent::MoveFast,
+            9 => InternalEvent::Rotate(rotate_turns),
+            10 => InternalEvent::LockTimer,
+            _ => InternalEvent::Countdown,
+        }
+    }
+}
+
+impl EventMap {
+    /// Schedules an event at the given time, returning the time at which an event of the same kind
+    /// was previously scheduled.
+    pub fn insert(&mut self, event: InternalEvent, event_time: GameTime) -> Option<GameTime> {
+        if let InternalEvent::Rotate(turns) = event {
+            self.rotate_turns = turns;
+        }
+        self.times[event.slot()].replace(event_time)
+    }
+
+    /// Returns the time at which the given event is scheduled.
+    pub fn get(&self, event: &InternalEvent) -> Option<GameTime> {
+        match event {
+            InternalEvent::Rotate(turns) if *turns != self.rotate_turns => None,
+            _ => self.times[event.slot()],
+        }
+    }
+
+    /// Whether the given event is scheduled.
+    pub fn contains_key(&self, event: &InternalEvent) -> bool {
+        self.get(event).is_some()
+    }
+
+    /// Unschedules the given event, returning the time at which it was scheduled.
+    pub fn remove(&mut self, event: &InternalEvent) -> Option<GameTime> {
+        if !self.contains_key(event) {
+            return None;
+        }
+        self.times[event.slot()].take()
+    }
+
+    /// Unschedules all events.
+    pub fn clear(&mut self) {
+        self.times = Default::default();
+    }
+
+    /// Whether no events are scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.times.iter().all(Option::is_none)
+    }
+
+    /// Iterates over all scheduled events and their times, in declaration order of the events.
+    pub fn iter(&self) -> impl Iterator<Item = (InternalEvent, GameTime)> + '_ {
+        self.times
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, event_time)| {
+                event_time.map(|event_time| {
+                    (
+                        InternalEvent::from_slot(slot, self.rotate_turns),
+                        event_time,
+                    )
+                })
+            })
+    }
+
+    /// Returns the earliest scheduled event.
+    ///
+    /// Ties are broken by the declaration order of [`InternalEvent`].
+    pub fn next_event(&self) -> Option<(InternalEvent, GameTime)> {
+        let mut next: Option<(usize, GameTime)> = None;
+        for (slot, event_time) in self.times.iter().enumerate() {
+            if let Some(event_time) = *event_time {
+                if next.map_or(true, |(_, next_time)| event_time < next_time) {
+                    next = Some((slot, event_time));
+                }
+            }
+        }
+        next.map(|(slot, event_time)| {
+            (
+                InternalEvent::from_slot(slot, self.rotate_turns),
+                event_time,
+            )
+        })
+    }
 }
 
 /// Represents how a game can end.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameOver {
-    /// 'Lock out' denotes the most recent piece being completely locked down at
-    /// or above the [`Game::SKYLINE`].
+    /// 'Lock out' denotes the most recent piece being locked down at or above the
+    /// [`GameConfig::skyline`], either completely or partially as set by [`GameConfig::topout_rule`].
     LockOut,
     /// 'Block out' denotes a new piece being unable to spawn due to pre-existing board tile
     /// blocking one or several of the spawn cells.
@@ -289,10 +953,9 @@ pub enum GameOver {
     Forfeit,
 }
 
-// TODO: Invariants:
-// * Until the game has finished there will always be more events: `finished.is_some() || !next_events.is_empty()`.
-// * Unhandled events lie in the future: `for (event,event_time) in self.events { assert(self.time_updated < event_time); }`.
 /// Struct storing internal game state that changes over the course of play.
+///
+/// Some invariants are expected to hold for the state of a running game, c.f. [`Game::validate_invariants`].
 #[derive(Eq, PartialEq, Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameState {
@@ -324,9 +987,59 @@ pub struct GameState {
     pub consecutive_line_clears: u32,
     /// The number of line clears that were either a quadruple, spin or perfect clear.
     pub back_to_back_special_clears: u32,
+    /// The total number of perfect clears achieved, i.e. line clears which left the board empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub perfect_clears: u32,
+    /// The total time added to the time limit by line clears, c.f. [`Limits::time_bonus`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub time_bonus: Duration,
+    /// Running totals of inputs made by the player.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_counts: InputCounts,
+    /// For each tetromino, the number of pieces spawned since it last spawned itself.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spawns_since_seen: [u32; 7],
+    /// The highest the stack has been built up over the game, measured after each lock.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_stack_height: usize,
+    /// The rotation to apply to the next spawned piece instead of inferring it from held buttons.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queued_initial_rotation: Option<i32>,
+    /// The hole column of each garbage line yet to be inserted, c.f. [`Game::queue_garbage`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queued_garbage: Vec<usize>,
+    /// The number of move and rotation inputs made since the last piece locked.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub piece_inputs: u32,
+    /// The number of pieces that were placed with more inputs than necessary.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub finesse_faults: u32,
+    /// Whether the held 'move' button has been held for [`GameConfig::delayed_auto_shift`], i.e.
+    /// auto-shifting is 'charged'.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub das_charged: bool,
+}
+
+/// Cumulative counts of the inputs a player made over the course of a game.
+///
+/// Each press of a button counts as one input, regardless of how long the button is held.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputCounts {
+    /// The number of 'move left' and 'move right' button presses.
+    pub moves: u32,
+    /// The number of 'rotate' button presses (in any direction).
+    pub rotations: u32,
+    /// The number of 'soft drop' button presses.
+    pub soft_drops: u32,
+    /// The number of 'hard drop' button presses.
+    pub hard_drops: u32,
+    /// The number of 'sonic drop' button presses.
+    pub sonic_drops: u32,
 }
 
 /// An error that can be thrown by [`Game::update`].
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Debug)]
 pub enum GameUpdateError {
     /// Error variant caused by an attempt to update the game with a requested `update_time` that lies in
     /// the game's past (` < game.state().time`).
@@ -340,8 +1053,34 @@ pub struct Game {
     config: GameConfig,
     mode: GameMode,
     state: GameState,
-    rng: ThreadRng,
+    rng: GameRng,
     modifiers: Vec<FnGameMod>,
+    observers: Vec<FnGameObserver>,
+    /// When the game was paused (on the caller's clock), if it currently is.
+    paused_at: Option<GameTime>,
+    /// The total time spent paused, by which the caller's clock is ahead of [`GameState::time`].
+    time_paused: Duration,
+}
+
+/// A serializable snapshot of a [`Game`], c.f. [`Game::save`] and [`Game::restore`].
+///
+/// Function-valued options in [`SavedGame::config`] are skipped when serializing and come back as
+/// their defaults, so a game using a custom [`GameConfig::scoring`] or
+/// [`GameConfig::lock_delay_curve`] needs them put back in after deserializing. A game using a
+/// [`GravityCurve::Custom`] cannot be serialized at all.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SavedGame {
+    /// The configuration of the saved game.
+    pub config: GameConfig,
+    /// The game mode of the saved game.
+    pub mode: GameMode,
+    /// The state of the saved game.
+    pub state: GameState,
+    /// The exact state of the game's random number generator.
+    ///
+    /// This is `None` if the game used [`RngSource::Thread`], whose state cannot be captured.
+    pub rng: Option<rng::Pcg32>,
 }
 
 /// A number of feedback events that can be returned by the game.
@@ -350,15 +1089,41 @@ pub struct Game {
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Feedback {
+    /// The countdown before the first piece spawns ticked, with the given time remaining.
+    Countdown(Duration),
+    /// The level increased to the given level after lines were cleared.
+    LevelUp(NonZeroU32),
+    /// A new piece was spawned onto the board.
+    Spawn {
+        /// The piece in its spawn position.
+        piece: ActivePiece,
+        /// The piece after its first fall following the spawn, which goes all the way down with 20G,
+        /// and any initial rotation (equal to `piece` if it neither falls nor rotates).
+        first_rest: ActivePiece,
+    },
     /// A piece was locked down in a certain configuration.
     PieceLocked(ActivePiece),
     /// A number of lines were cleared.
     ///
     /// The duration indicates the line clear delay the game was configured with at the time.
     LineClears(Vec<usize>, Duration),
-    /// A piece was quickly dropped from its original position to a new one.
-    // TODO: Rename to `QuickDrop` and make the `InternalEvent::DropSonic` cause this too?
-    HardDrop(ActivePiece, ActivePiece),
+    /// How the remaining lines will collapse after a number of lines were cleared.
+    ///
+    /// Each entry is the index of a surviving row (before the clear) together with the number of
+    /// rows it will be shifted down by. Rows which do not move are omitted.
+    LineShifts(Vec<(usize, usize)>),
+    /// The board was modified by a piece locking down or lines being cleared.
+    ///
+    /// Only emitted if [`GameConfig::emit_board_changes`] is set.
+    BoardChanged {
+        /// The board before the change.
+        before: Board,
+        /// The board after the change.
+        after: Board,
+    },
+    /// A piece was quickly dropped from its original position to a new one, by either a hard drop
+    /// or a sonic drop.
+    QuickDrop(ActivePiece, ActivePiece),
     /// The player cleared some lines with a number of other stats that might have increased their
     /// score bonus.
     Accolade {
@@ -366,8 +1131,8 @@ pub enum Feedback {
         score_bonus: u32,
         /// The shape that was locked.
         shape: Tetromino,
-        /// Whether the piece was spun into place.
-        spin: bool,
+        /// Whether and how the piece was spun into place.
+        spin: SpinKind,
         /// How many lines were cleared by the piece simultaneously
         lineclears: u32,
         /// Whether the entire board was cleared empty by this action.
@@ -377,12 +1142,155 @@ pub enum Feedback {
         /// The number of consecutive lineclears where a spin, quadruple or perfect clear occurred.
         back_to_back: u32,
     },
+    /// The time limit was extended by the given amount after lines were cleared, c.f.
+    /// [`Limits::time_bonus`].
+    TimeBonus(Duration),
+    /// The board was cleared entirely, emitted alongside the corresponding [`Feedback::Accolade`].
+    PerfectClear {
+        /// How many lines were cleared by the piece simultaneously.
+        lines: u32,
+    },
+    /// Garbage lines were pushed onto the board from below, given by the hole column of each line
+    /// from top to bottom.
+    GarbageInserted(Vec<usize>),
+    /// A piece was locked down with more move and rotation inputs than necessary.
+    FinesseFault {
+        /// The piece that was locked.
+        piece: ActivePiece,
+        /// The number of inputs made to place the piece.
+        inputs: u32,
+        /// The least number of inputs needed to place the piece.
+        optimal: u32,
+    },
     /// Generic text feedback message.
     ///
     /// This is currently unused in base game modes.
     Message(String),
 }
 
+/// Describes a line clear to be scored by a [`ScoreFn`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+pub struct ScoreEvent {
+    /// How many lines were cleared by the piece simultaneously.
+    pub lineclears: u32,
+    /// Whether and how the piece was spun into place.
+    pub spin: SpinKind,
+    /// Whether the entire board was cleared empty by this action.
+    pub perfect_clear: bool,
+    /// The number of consecutive pieces played that caused a lineclear, including this one.
+    pub combo: u32,
+    /// The number of consecutive lineclears where a spin, quadruple or perfect clear occurred,
+    /// including this one.
+    pub back_to_back: u32,
+    /// The level at which the lines were cleared.
+    pub level: NonZeroU32,
+}
+
+impl ScoreEvent {
+    /// The default scoring formula.
+    ///
+    /// The score grows quadratically with the lines cleared plus combo, and is multiplied by the
+    /// back-to-back count, a spin bonus (x2 for minis, x4 otherwise) and x100 for perfect clears.
+    /// The level is not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// Custom scoring can be set up as follows:
+    ///
+    /// ```
+    /// use tetrs_engine::{GameConfig, ScoreEvent, SpinKind};
+    ///
+    /// // Score by a fixed table per number of lines cleared, scaled by level.
+    /// fn ladder_score(event: &ScoreEvent) -> u32 {
+    ///     let base = [0, 100, 300, 500, 800][event.lineclears.min(4) as usize];
+    ///     base * event.level.get()
+    /// }
+    /// let config = GameConfig { scoring: ladder_score, ..GameConfig::default() };
+    ///
+    /// let double = ScoreEvent {
+    ///     lineclears: 2,
+    ///     spin: SpinKind::None,
+    ///     perfect_clear: false,
+    ///     combo: 1,
+    ///     back_to_back: 0,
+    ///     level: 3.try_into().unwrap(),
+    /// };
+    /// assert_eq!((config.scoring)(&double), 900);
+    /// assert_eq!(ScoreEvent::default_score(&double), 40);
+    /// ```
+    pub fn default_score(&self) -> u32 {
+        // NOTE: Extreme combos could overflow, so all score arithmetic saturates.
+        10u32
+            .saturating_mul(
+                self.lineclears
+                    .saturating_add(self.combo)
+                    .saturating_sub(1)
+                    .saturating_pow(2),
+            )
+            .saturating_mul(self.back_to_back.max(1))
+            .saturating_mul(match self.spin {
+                SpinKind::None => 1,
+                SpinKind::Mini => 2,
+                SpinKind::Full | SpinKind::Immobile => 4,
+            })
+            .saturating_mul(if self.perfect_clear { 100 } else { 1 })
+    }
+
+    /// The time gained for a line clear if [`Limits::time_bonus`] is enabled.
+    ///
+    /// One second is gained for a single, doubling with each additional line cleared. Spins double
+    /// the time gained, back-to-back clears add one more second and perfect clears add ten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{ScoreEvent, SpinKind};
+    ///
+    /// let tsd = ScoreEvent {
+    ///     lineclears: 2,
+    ///     spin: SpinKind::Full,
+    ///     perfect_clear: false,
+    ///     combo: 1,
+    ///     back_to_back: 2,
+    ///     level: 1.try_into().unwrap(),
+    /// };
+    /// assert_eq!(tsd.default_time_bonus(), Duration::from_secs(5));
+    /// ```
+    pub fn default_time_bonus(&self) -> Duration {
+        if self.lineclears == 0 {
+            return Duration::ZERO;
+        }
+        let mut secs = 1u64 << (self.lineclears - 1).min(8);
+        if self.spin != SpinKind::None {
+            secs *= 2;
+        }
+        if self.back_to_back > 1 {
+            secs += 1;
+        }
+        if self.perfect_clear {
+            secs += 10;
+        }
+        Duration::from_secs(secs)
+    }
+}
+
+/// Represents whether and how a piece was spun into its final position.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpinKind {
+    /// The piece was not spun.
+    None,
+    /// A T-piece was rotated into a spot with three occupied corners around its center, but only
+    /// one of the two corners it points towards is occupied ('T-spin mini').
+    Mini,
+    /// A T-piece was rotated into a spot with three occupied corners around its center, including
+    /// both corners it points towards.
+    Full,
+    /// A piece other than T was rotated into a spot from which it could not move up, left or right.
+    Immobile,
+}
+
 /// The points at which a [`FnGameMod`] will be applied.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
 pub enum ModifierPoint {
@@ -474,6 +1382,36 @@ impl Tetromino {
         // SAFETY: Ye, `u8 > 0`;
         unsafe { NonZeroU32::new_unchecked(u8) }
     }
+
+    /// Returns the tetromino whose standard tile id (c.f. [`Tetromino::tiletypeid`]) is the given
+    /// one, or `None` for any other tile id such as [`Game::GARBAGE_TILE`].
+    pub const fn from_tiletypeid(tile_type_id: TileTypeID) -> Option<Self> {
+        use Tetromino::*;
+        Some(match tile_type_id.get() {
+            1 => O,
+            2 => I,
+            3 => S,
+            4 => Z,
+            5 => T,
+            6 => L,
+            7 => J,
+            _ => return None,
+        })
+    }
+
+    /// Returns the letter the tetromino is named after, e.g. `'T'` for [`Tetromino::T`].
+    pub const fn name_char(&self) -> char {
+        use Tetromino::*;
+        match self {
+            O => 'O',
+            I => 'I',
+            S => 'S',
+            Z => 'Z',
+            T => 'T',
+            L => 'L',
+            J => 'J',
+        }
+    }
 }
 
 impl TryFrom<usize> for Tetromino {
@@ -538,11 +1476,23 @@ impl ActivePiece {
             .map(|(dx, dy)| ((x + dx, y + dy), tile_type_id))
     }
 
+    /// Returns the coordinates and tile types for those tiles of the piece which lie below a
+    /// given `visible_height`, c.f. [`GameConfig::skyline`].
+    pub fn visible_tiles(&self, visible_height: usize) -> Vec<(Coord, TileTypeID)> {
+        self.tiles()
+            .into_iter()
+            .filter(|&((_, y), _)| y < visible_height)
+            .collect()
+    }
+
     /// Checks whether the piece fits at its current location onto the board.
     pub fn fits(&self, board: &Board) -> bool {
-        self.tiles()
-            .iter()
-            .all(|&((x, y), _)| x < Game::WIDTH && y < Game::HEIGHT && board[y][x].is_none())
+        self.tiles().iter().all(|&((x, y), _)| {
+            board
+                .get(y)
+                .and_then(|line| line.get(x))
+                .is_some_and(|cell| cell.is_none())
+        })
     }
 
     /// Checks whether the piece fits a given offset from its current location onto the board.
@@ -593,6 +1543,12 @@ impl ActivePiece {
         }
         well_piece
     }
+
+    /// Returns the tiles of the piece's 'ghost', i.e. where it would land if dropped straight down
+    /// onto the given board.
+    pub fn ghost_tiles(&self, board: &Board) -> [(Coord, TileTypeID); 4] {
+        self.well_piece(board).tiles()
+    }
 }
 
 impl GameMode {
@@ -603,6 +1559,7 @@ impl GameMode {
     /// - Start level: 1.
     /// - Level increment: Yes.
     /// - Limits: Level 19.
+    /// - Objective: Score.
     pub fn marathon() -> Self {
         Self {
             name: String::from("Marathon"),
@@ -612,6 +1569,8 @@ impl GameMode {
                 level: Some((true, Game::LEVEL_20G)),
                 ..Default::default()
             },
+            objective: Some(Objective::Score),
+            recommended_config: None,
         }
     }
 
@@ -622,6 +1581,7 @@ impl GameMode {
     /// - Start level: (variable).
     /// - Level increment: No.
     /// - Limits: 40 line clears.
+    /// - Objective: Time.
     pub fn sprint(start_level: NonZeroU32) -> Self {
         Self {
             name: String::from("40-Lines"),
@@ -631,6 +1591,8 @@ impl GameMode {
                 lines: Some((true, 40)),
                 ..Default::default()
             },
+            objective: Some(Objective::Time),
+            recommended_config: None,
         }
     }
 
@@ -641,6 +1603,7 @@ impl GameMode {
     /// - Start level: (variable).
     /// - Level increment: No.
     /// - Limits: 180 seconds.
+    /// - Objective: Lines.
     pub fn ultra(start_level: NonZeroU32) -> Self {
         Self {
             name: String::from("Time Trial"),
@@ -650,6 +1613,8 @@ impl GameMode {
                 time: Some((true, Duration::from_secs(3 * 60))),
                 ..Default::default()
             },
+            objective: Some(Objective::Lines),
+            recommended_config: None,
         }
     }
 
@@ -657,9 +1622,10 @@ impl GameMode {
     ///
     /// Settings:
     /// - Name: "Master".
-    /// - Start level: 19.
+    /// - Start level: 20.
     /// - Level increment: Yes.
     /// - Limits: 300 Lines.
+    /// - Objective: Lines.
     pub fn master() -> Self {
         Self {
             name: String::from("Master"),
@@ -669,6 +1635,97 @@ impl GameMode {
                 lines: Some((true, 300)),
                 ..Default::default()
             },
+            objective: Some(Objective::Lines),
+            recommended_config: None,
+        }
+    }
+
+    /// Produce a game mode template for "Death" mode.
+    ///
+    /// Settings:
+    /// - Name: "Death".
+    /// - Start level: 26.
+    /// - Level increment: Yes.
+    /// - Limits: 40 Lines.
+    /// - Objective: Lines.
+    pub fn death() -> Self {
+        Self {
+            name: String::from("Death"),
+            start_level: NonZeroU32::try_from(26).unwrap(),
+            increment_level: true,
+            limits: Limits {
+                lines: Some((true, 40)),
+                ..Default::default()
+            },
+            objective: Some(Objective::Lines),
+            recommended_config: None,
+        }
+    }
+
+    /// Produce a game mode template for "Finesse" mode.
+    ///
+    /// Settings:
+    /// - Name: "Finesse".
+    /// - Start level: 1.
+    /// - Level increment: No.
+    /// - Limits: (variable) pieces.
+    /// - Objective: Finesse faults.
+    pub fn finesse(pieces: u32) -> Self {
+        Self {
+            name: String::from("Finesse"),
+            start_level: NonZeroU32::MIN,
+            increment_level: false,
+            limits: Limits {
+                pieces: Some((true, pieces)),
+                ..Default::default()
+            },
+            objective: Some(Objective::FinesseFaults),
+            recommended_config: None,
+        }
+    }
+
+    /// Produce a game mode template for "Perfect Clear" mode.
+    ///
+    /// Settings:
+    /// - Name: "Perfect Clear".
+    /// - Start level: 1.
+    /// - Level increment: No.
+    /// - Limits: (variable) perfect clears.
+    /// - Objective: Time.
+    pub fn perfect_clear(count: u32) -> Self {
+        Self {
+            name: String::from("Perfect Clear"),
+            start_level: NonZeroU32::MIN,
+            increment_level: false,
+            limits: Limits {
+                perfect_clears: Some((true, count)),
+                ..Default::default()
+            },
+            objective: Some(Objective::Time),
+            recommended_config: None,
+        }
+    }
+
+    /// Produce a game mode template for "Increment" mode.
+    ///
+    /// Settings:
+    /// - Name: "Increment".
+    /// - Start level: 1.
+    /// - Level increment: Yes.
+    /// - Limits: 60 seconds, extended by line clears.
+    /// - Objective: Lines.
+    pub fn increment() -> Self {
+        Self {
+            name: String::from("Increment"),
+            start_level: NonZeroU32::MIN,
+            increment_level: true,
+            limits: Limits {
+                time: Some((true, Duration::from_secs(60))),
+                time_bonus: true,
+                ..Default::default()
+            },
+            objective: Some(Objective::Lines),
+            recommended_config: None,
         }
     }
 
@@ -679,13 +1736,92 @@ impl GameMode {
     /// - Start level: 1.
     /// - Level increment: No.
     /// - Limits: None.
+    /// - Objective: None.
     pub fn zen() -> Self {
         Self {
             name: String::from("Endless"),
             start_level: NonZeroU32::MIN,
             increment_level: false,
             limits: Default::default(),
+            objective: None,
+            recommended_config: None,
+        }
+    }
+}
+
+impl InputCounts {
+    /// The total number of inputs made.
+    pub fn total(&self) -> u32 {
+        self.moves + self.rotations + self.soft_drops + self.hard_drops + self.sonic_drops
+    }
+}
+
+impl GameState {
+    /// The average number of inputs made per minute of game time.
+    ///
+    /// Returns `0.0` at the start of the game.
+    pub fn apm(&self) -> f64 {
+        let minutes = self.time.as_secs_f64() / 60.0;
+        if minutes == 0.0 {
+            return 0.0;
+        }
+        f64::from(self.input_counts.total()) / minutes
+    }
+
+    /// The average number of pieces locked per second of game time.
+    ///
+    /// Returns `0.0` at the start of the game.
+    pub fn pps(&self) -> f64 {
+        let seconds = self.time.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
         }
+        f64::from(self.pieces_played.iter().sum::<u32>()) / seconds
+    }
+
+    /// The average number of inputs made per piece locked.
+    ///
+    /// Returns `0.0` if no pieces have been locked yet.
+    pub fn kpp(&self) -> f64 {
+        let pieces = self.pieces_played.iter().sum::<u32>();
+        if pieces == 0 {
+            return 0.0;
+        }
+        f64::from(self.input_counts.total()) / f64::from(pieces)
+    }
+
+    /// A fast fingerprint of the board's contents, e.g. to detect desynchronization between two
+    /// games that are fed the same inputs.
+    ///
+    /// This is a 64-bit FNV-1a hash over all cells, so it is stable across platforms and versions.
+    pub fn board_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const PRIME: u64 = 0x100000001b3;
+        self.board
+            .iter()
+            .flatten()
+            .flat_map(|cell| cell.map_or(0, NonZeroU32::get).to_le_bytes())
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+            })
+    }
+
+    /// For each tetromino, how many pieces have spawned since it was last seen ('drought').
+    ///
+    /// The array is indexed by [`Tetromino`]. A tetromino that has not spawned yet counts all
+    /// pieces spawned so far.
+    pub fn drought(&self) -> [u32; 7] {
+        self.spawns_since_seen
+    }
+
+    /// Returns the visible tiles of the active piece, if any, as given by
+    /// [`ActivePiece::visible_tiles`].
+    ///
+    /// Pieces spawn in the buffer zone above the [`GameConfig::skyline`], so this can be used by a
+    /// frontend to show a piece entering from the top of the visible playing grid.
+    pub fn active_piece_clamped(&self, visible_height: usize) -> Option<Vec<(Coord, TileTypeID)>> {
+        self.active_piece_data
+            .map(|(active_piece, _)| active_piece.visible_tiles(visible_height))
     }
 }
 
@@ -724,6 +1860,9 @@ impl<T> ops::IndexMut<Button> for [T; 8] {
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
+            width: Game::WIDTH,
+            height: Game::HEIGHT,
+            skyline: Game::SKYLINE,
             rotation_system: RotationSystem::Ocular,
             tetromino_generator: TetrominoGenerator::recency(),
             preview_count: 1,
@@ -732,9 +1871,139 @@ impl Default for GameConfig {
             soft_drop_factor: 15.0,
             hard_drop_delay: Duration::from_micros(100),
             ground_time_max: Duration::from_millis(2250),
+            lock_delay_max_resets: None,
             line_clear_delay: Duration::from_millis(200),
             appearance_delay: Duration::from_millis(50),
             no_soft_drop_lock: false,
+            drop_scoring: false,
+            spawn_row: SpawnRow::Skyline,
+            spawn_retry_rows: 0,
+            soft_drop_mode: SoftDropMode::Continuous,
+            instant_lock: false,
+            rng_source: RngSource::Thread,
+            start_countdown: Duration::ZERO,
+            topout_policy: TopoutPolicy::End,
+            topout_rule: TopoutRule::AllAboveSkyline,
+            emit_board_changes: false,
+            initial_rotation: true,
+            twenty_g: TwentyG::Auto(Game::LEVEL_20G.saturating_add(1)),
+            gravity_curve: GravityCurve::Guideline,
+            lock_delay_curve: Game::guideline_lock_delay,
+            max_catchup: None,
+            das_carryover: true,
+            show_ghost: true,
+            combo_rule: ComboRule::LineClearsOnly,
+            o_rotation: ORotation::NoOp,
+            line_clear_gravity: LineClearGravity::Naive,
+            scoring: ScoreEvent::default_score,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Returns an empty board with the configured dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetrs_engine::GameConfig;
+    ///
+    /// let config = GameConfig { width: 6, ..GameConfig::default() };
+    /// let board = config.empty_board();
+    /// assert_eq!(board.len(), config.height);
+    /// assert!(board.iter().all(|line| line.len() == 6 && line.iter().all(|cell| cell.is_none())));
+    /// ```
+    pub fn empty_board(&self) -> Board {
+        vec![self.empty_line(); self.height]
+    }
+
+    /// Returns an empty line with the configured width.
+    fn empty_line(&self) -> Line {
+        vec![None; self.width].into_boxed_slice()
+    }
+
+    /// Produce a configuration resembling the modern guideline standard.
+    ///
+    /// Settings:
+    /// - Rotation system: Super.
+    /// - Tetromino generator: 7-bag.
+    /// - Preview: 5 pieces.
+    /// - DAS / ARR: 167ms / 33ms.
+    /// - Soft drop factor: 20.
+    /// - Lock delay resets: 15.
+    /// - Remaining options: As in [`GameConfig::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tetrs_engine::{piece_generation::TetrominoGenerator, piece_rotation::RotationSystem, GameConfig};
+    ///
+    /// let config = GameConfig::guideline();
+    /// assert_eq!(config.rotation_system, RotationSystem::Super);
+    /// assert!(matches!(config.tetromino_generator, TetrominoGenerator::Bag { multiplicity, .. } if multiplicity.get() == 1));
+    /// assert!(config.delayed_auto_shift > GameConfig::modern_competitive().delayed_auto_shift);
+    /// ```
+    pub fn guideline() -> Self {
+        Self {
+            rotation_system: RotationSystem::Super,
+            tetromino_generator: TetrominoGenerator::seven_bag(),
+            preview_count: 5,
+            delayed_auto_shift: Duration::from_millis(167),
+            auto_repeat_rate: Duration::from_millis(33),
+            soft_drop_factor: 20.0,
+            lock_delay_max_resets: Some(15),
+            ..Self::default()
+        }
+    }
+
+    /// Produce a configuration resembling classic NES-era games.
+    ///
+    /// Settings:
+    /// - Rotation system: Classic.
+    /// - Tetromino generator: Uniform.
+    /// - Preview: 1 piece.
+    /// - DAS / ARR: 267ms / 100ms.
+    /// - Soft drop factor: 2.
+    /// - Line clear / appearance delay: 333ms / 167ms.
+    /// - Pieces lock instantly upon landing and never fall at 20G.
+    /// - No initial rotation and no ghost piece.
+    /// - Remaining options: As in [`GameConfig::default`].
+    pub fn classic() -> Self {
+        Self {
+            rotation_system: RotationSystem::Classic,
+            tetromino_generator: TetrominoGenerator::uniform(),
+            preview_count: 1,
+            delayed_auto_shift: Duration::from_millis(267),
+            auto_repeat_rate: Duration::from_millis(100),
+            soft_drop_factor: 2.0,
+            line_clear_delay: Duration::from_millis(333),
+            appearance_delay: Duration::from_millis(167),
+            instant_lock: true,
+            twenty_g: TwentyG::Never,
+            initial_rotation: false,
+            show_ghost: false,
+            ..Self::default()
+        }
+    }
+
+    /// Produce a configuration tuned for fast competitive play.
+    ///
+    /// Settings:
+    /// - Rotation system: Super.
+    /// - Tetromino generator: 7-bag.
+    /// - Preview: 5 pieces.
+    /// - DAS / ARR: 100ms / 0ms.
+    /// - Soft drop factor: 40.
+    /// - Line clear / appearance delay: None.
+    /// - Remaining options: As in [`GameConfig::default`].
+    pub fn modern_competitive() -> Self {
+        Self {
+            delayed_auto_shift: Duration::from_millis(100),
+            auto_repeat_rate: Duration::ZERO,
+            soft_drop_factor: 40.0,
+            line_clear_delay: Duration::ZERO,
+            appearance_delay: Duration::ZERO,
+            ..Self::guideline()
         }
     }
 }
@@ -746,17 +2015,30 @@ impl fmt::Debug for Game {
             .field("state", &self.state)
             .field("rng", &std::any::type_name_of_val(&self.rng))
             .field("modifiers", &std::any::type_name_of_val(&self.modifiers))
+            .field("observers", &std::any::type_name_of_val(&self.observers))
+            .field("paused_at", &self.paused_at)
+            .field("time_paused", &self.time_paused)
             .finish()
     }
 }
 
 impl Game {
-    /// The maximum height *any* piece tile could reach before [`GameOver::LockOut`] occurs.
+    /// The default [`GameConfig::height`].
     pub const HEIGHT: usize = Self::SKYLINE + 7;
-    /// The game field width.
+    /// The default [`GameConfig::width`].
     pub const WIDTH: usize = 10;
-    /// The maximal height of the (conventionally visible) playing grid that can be played in.
+    /// The default [`GameConfig::skyline`].
     pub const SKYLINE: usize = 20;
+    /// The [`TileTypeID`] of the tiles in garbage lines inserted by [`Game::queue_garbage`].
+    // SAFETY: 254 > 0.
+    pub const GARBAGE_TILE: TileTypeID = unsafe { NonZeroU32::new_unchecked(254) };
+    /// The [`TileTypeID`] reserved for any other tiles not belonging to a tetromino, e.g. walls
+    /// placed by game mods.
+    ///
+    /// Tile ids `1..=7` are taken by the [`Tetromino`]s (c.f. [`Tetromino::tiletypeid`]), and ids
+    /// up to and including this one should be considered reserved by the engine.
+    // SAFETY: 255 > 0.
+    pub const WALL_TILE: TileTypeID = unsafe { NonZeroU32::new_unchecked(255) };
     // SAFETY: 19 > 0, and this is the level at which blocks start falling with 20G.
     const LEVEL_20G: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(19) };
 
@@ -765,16 +2047,78 @@ impl Game {
         Self::with_config(game_mode, GameConfig::default())
     }
 
+    /// Start a new game given some game mode, with all randomness derived from the given seed.
+    ///
+    /// Two games started with the same mode and seed produce identical piece sequences given
+    /// identical inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{Game, GameMode};
+    ///
+    /// let mut game1 = Game::with_seed(GameMode::zen(), 42);
+    /// let mut game2 = Game::with_seed(GameMode::zen(), 42);
+    /// game1.update(None, Duration::from_secs(10)).unwrap();
+    /// game2.update(None, Duration::from_secs(10)).unwrap();
+    /// assert_eq!(game1.state(), game2.state());
+    /// assert_eq!(game1.seed(), Some(42));
+    /// ```
+    pub fn with_seed(game_mode: GameMode, seed: u64) -> Self {
+        Self::with_config_and_seed(game_mode, GameConfig::default(), seed)
+    }
+
+    /// Start a new game given a gamemode and some advanced configuration options, with all
+    /// randomness derived from the given seed.
+    ///
+    /// This overrides [`GameConfig::rng_source`], c.f. [`Game::with_seed`].
+    pub fn with_config_and_seed(game_mode: GameMode, mut config: GameConfig, seed: u64) -> Self {
+        config.rng_source = RngSource::Pcg32 { seed };
+        Self::with_config(game_mode, config)
+    }
+
     /// Start a new game given a gamemode and some advanced configuration options.
-    pub fn with_config(game_mode: GameMode, config: GameConfig) -> Self {
-        let state = GameState {
+    pub fn with_config(game_mode: GameMode, mut config: GameConfig) -> Self {
+        let mut rng = GameRng::from_source(&config.rng_source);
+        Self::shuffle_recency_history(&mut config.tetromino_generator, &mut rng);
+        let state = Self::initial_state(&game_mode, &config, config.empty_board());
+        Game {
+            rng,
+            config,
+            mode: game_mode,
+            state,
+            modifiers: Vec::new(),
+            observers: Vec::new(),
+            paused_at: None,
+            time_paused: Duration::ZERO,
+        }
+    }
+
+    /// Redraws the random starting history of a [`TetrominoGenerator::Recency`] from the game's own
+    /// source of randomness, so that seeded games are reproducible.
+    fn shuffle_recency_history(tetromino_generator: &mut TetrominoGenerator, rng: &mut GameRng) {
+        if let TetrominoGenerator::Recency { last_generated } = tetromino_generator {
+            *last_generated = [0, 1, 2, 3, 4, 5, 6];
+            rng::shuffle(rng, last_generated);
+        }
+    }
+
+    /// The state of a game that has just started, with the given (empty) board.
+    fn initial_state(game_mode: &GameMode, config: &GameConfig, board: Board) -> GameState {
+        GameState {
             time: Duration::ZERO,
             end: None,
-            events: HashMap::from([(InternalEvent::Spawn, Duration::ZERO)]),
+            events: {
+                let mut events = EventMap::default();
+                events.insert(InternalEvent::Spawn, config.start_countdown);
+                if !config.start_countdown.is_zero() {
+                    events.insert(InternalEvent::Countdown, Duration::ZERO);
+                }
+                events
+            },
             buttons_pressed: Default::default(),
-            board: std::iter::repeat(Line::default())
-                .take(Self::HEIGHT)
-                .collect(),
+            board,
             active_piece_data: None,
             next_pieces: VecDeque::new(),
             pieces_played: [0; 7],
@@ -783,14 +2127,247 @@ impl Game {
             score: 0,
             consecutive_line_clears: 0,
             back_to_back_special_clears: 0,
+            perfect_clears: 0,
+            time_bonus: Duration::ZERO,
+            input_counts: InputCounts::default(),
+            spawns_since_seen: [0; 7],
+            max_stack_height: 0,
+            queued_initial_rotation: None,
+            queued_garbage: Vec::new(),
+            piece_inputs: 0,
+            finesse_faults: 0,
+            das_charged: false,
+        }
+    }
+
+    /// Resets the game to its very beginning, e.g. to quickly retry a round.
+    ///
+    /// The configuration, game mode and game modifiers are kept; modifiers are therefore called
+    /// again for the new round and must handle being restarted themselves if they carry any state.
+    /// The random number generator and piece generator are reinitialized, so a game using
+    /// [`RngSource::Pcg32`] is replayed with the same pieces as [`Game::with_config_and_seed`]
+    /// would produce.
+    pub fn restart(&mut self) {
+        self.rng = GameRng::from_source(&self.config.rng_source);
+        // NOTE: Cloning a generator resets its state.
+        self.config.tetromino_generator = self.config.tetromino_generator.clone();
+        Self::shuffle_recency_history(&mut self.config.tetromino_generator, &mut self.rng);
+        // Reuse the allocated board where possible.
+        let mut board = std::mem::take(&mut self.state.board);
+        board.truncate(self.config.height);
+        for line in &mut board {
+            if line.len() == self.config.width {
+                line.fill(None);
+            } else {
+                *line = self.config.empty_line();
+            }
+        }
+        board.resize(self.config.height, self.config.empty_line());
+        self.state = Self::initial_state(&self.mode, &self.config, board);
+        self.paused_at = None;
+        self.time_paused = Duration::ZERO;
+    }
+
+    /// Start a game from an arbitrary, already ongoing game state.
+    ///
+    /// This can be used to resume a game, or to set up specific scenarios (e.g. one line short of
+    /// completing a game).
+    ///
+    /// # Errors
+    ///
+    /// This function returns a description of the problem if the given state violates any of the
+    /// invariants checked by [`Game::validate_invariants`] (e.g. the board has the wrong height or
+    /// no events are scheduled in an ongoing game).
+    pub fn with_initial_state(
+        game_mode: GameMode,
+        config: GameConfig,
+        state: GameState,
+    ) -> Result<Self, String> {
+        let game = Game {
+            rng: GameRng::from_source(&config.rng_source),
+            config,
+            mode: game_mode,
+            state,
+            modifiers: Vec::new(),
+            observers: Vec::new(),
+            paused_at: None,
+            time_paused: Duration::ZERO,
+        };
+        game.validate_invariants()?;
+        Ok(game)
+    }
+
+    /// Takes a serializable snapshot of the game, e.g. to store a paused game on disk.
+    ///
+    /// Game modifiers cannot be saved and are not part of the snapshot.
+    #[cfg(feature = "serde")]
+    pub fn save(&self) -> SavedGame {
+        // NOTE: Cloning the config would reset the state of its tetromino generator.
+        let config = GameConfig {
+            tetromino_generator: self.config.tetromino_generator.clone_with_state(),
+            ..self.config.clone()
         };
+        SavedGame {
+            config,
+            mode: self.mode.clone(),
+            state: self.state.clone(),
+            rng: match &self.rng {
+                GameRng::Thread(_) => None,
+                GameRng::Pcg32(rng) => Some(rng.clone()),
+            },
+        }
+    }
+
+    /// Continues a game from a snapshot taken by [`Game::save`].
+    ///
+    /// If the snapshot contains the state of the random number generator, the restored game
+    /// generates exactly the same pieces as the original game would have. Otherwise a fresh
+    /// generator is created from [`GameConfig::rng_source`].
+    ///
+    /// Game modifiers of the original game are not restored, and must be re-added if needed.
+    #[cfg(feature = "serde")]
+    pub fn restore(saved_game: SavedGame) -> Self {
+        let SavedGame {
+            config,
+            mode,
+            state,
+            rng,
+        } = saved_game;
         Game {
+            rng: rng.map_or_else(|| GameRng::from_source(&config.rng_source), GameRng::Pcg32),
             config,
-            mode: game_mode,
+            mode,
             state,
-            rng: rand::thread_rng(),
             modifiers: Vec::new(),
+            observers: Vec::new(),
+            paused_at: None,
+            time_paused: Duration::ZERO,
+        }
+    }
+
+    /// Replaces the active piece by the given piece, e.g. to set up specific scenarios.
+    ///
+    /// The piece starts with fresh locking data, and its fall timer is restarted.
+    /// If no piece is currently in play, the given piece takes the place of the next spawned piece.
+    ///
+    /// # Errors
+    ///
+    /// This function fails without changing the game if the game has ended, a line clear is
+    /// still pending, or the given piece does not fit onto the board.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_active_piece(&mut self, piece: ActivePiece) -> Result<(), ()> {
+        if self.ended()
+            || self.state.events.contains_key(&InternalEvent::LineClear)
+            || !piece.fits(&self.state.board)
+        {
+            return Err(());
+        }
+        let time = self.state.time;
+        for event in [
+            InternalEvent::Spawn,
+            InternalEvent::Lock,
+            InternalEvent::LockTimer,
+        ] {
+            self.state.events.remove(&event);
+        }
+        let drop_delay = self.drop_delay(self.held_soft_drop_factor());
+        self.state
+            .events
+            .insert(InternalEvent::Fall, time + drop_delay);
+        let touches_ground = piece.fits_at(&self.state.board, (0, -1)).is_none();
+        let locking_data =
+            self.calculate_locking_data(InternalEvent::Spawn, time, None, piece, touches_ground);
+        self.state.active_piece_data = Some((piece, locking_data));
+        Ok(())
+    }
+
+    /// Queues a number of garbage lines to be pushed onto the board from below, each with one empty
+    /// cell at the given column.
+    ///
+    /// Garbage is inserted once a piece locks without clearing lines, shifting the stack up.
+    /// If this pushes tiles off the top of the board, or above the skyline into the cells the next
+    /// piece spawns in, the game ends with [`GameOver::LockOut`] (unless
+    /// [`GameConfig::topout_policy`] makes room).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hole_column` is not less than [`GameConfig::width`].
+    pub fn queue_garbage(&mut self, rows: usize, hole_column: usize) {
+        assert!(
+            hole_column < self.config.width,
+            "garbage hole column {hole_column} out of bounds"
+        );
+        self.state
+            .queued_garbage
+            .extend(std::iter::repeat(hole_column).take(rows));
+    }
+
+    /// Sets the number of right turns to rotate the next spawned piece by ('IRS').
+    ///
+    /// This overrides inferring the initial rotation from held rotation buttons for that one piece,
+    /// and applies even if [`GameConfig::initial_rotation`] is disabled.
+    pub fn queue_initial_rotation(&mut self, right_turns: i32) {
+        self.state.queued_initial_rotation = Some(right_turns);
+    }
+
+    /// Empties the board and restarts play with a freshly spawned piece, e.g. for practice resets.
+    ///
+    /// Unlike starting a new game, all statistics such as score, level, lines cleared and time are
+    /// kept. The active piece, if any, is discarded. Does nothing if the game has ended.
+    pub fn clear_board_keep_stats(&mut self) {
+        if self.ended() {
+            return;
         }
+        // Finish a pending line clear first so the cleared lines are still counted.
+        if self
+            .state
+            .events
+            .remove(&InternalEvent::LineClear)
+            .is_some()
+        {
+            self.handle_event(InternalEvent::LineClear, self.state.time);
+        }
+        self.state.board = self.config.empty_board();
+        self.state.active_piece_data = None;
+        // Keep a pending spawn (e.g. still counting down), otherwise spawn right away.
+        let spawn_time = self
+            .state
+            .events
+            .get(&InternalEvent::Spawn)
+            .unwrap_or(self.state.time);
+        let countdown_time = self.state.events.get(&InternalEvent::Countdown);
+        self.state.events.clear();
+        self.state.events.insert(InternalEvent::Spawn, spawn_time);
+        if let Some(countdown_time) = countdown_time {
+            self.state
+                .events
+                .insert(InternalEvent::Countdown, countdown_time);
+        }
+    }
+
+    /// Pauses the game at the given time, after which [`Game::update`] does nothing until the game is
+    /// resumed.
+    ///
+    /// Pausing an already paused game has no effect.
+    pub fn pause(&mut self, at: GameTime) {
+        self.paused_at.get_or_insert(at);
+    }
+
+    /// Resumes a paused game at the given time.
+    ///
+    /// The time spent paused is not counted as game time: all times subsequently passed to
+    /// [`Game::update`], [`Game::pause`] and [`Game::resume`] are shifted back by it, so callers
+    /// can keep feeding the real time elapsed since the start of the game.
+    /// Resuming a game that is not paused has no effect.
+    pub fn resume(&mut self, at: GameTime) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.time_paused += at.saturating_sub(paused_at);
+        }
+    }
+
+    /// Whether the game is currently paused, c.f. [`Game::pause`].
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
     }
 
     /// Immediately end a game by forfeiting the current round.
@@ -806,6 +2383,42 @@ impl Game {
         self.state.end.is_some()
     }
 
+    /// The seed all of the game's randomness was derived from, if it was started with one.
+    ///
+    /// A finished game can be re-instantiated with [`Game::with_config_and_seed`] using this seed.
+    pub fn seed(&self) -> Option<u64> {
+        match self.config.rng_source {
+            RngSource::Thread => None,
+            RngSource::Pcg32 { seed } => Some(seed),
+        }
+    }
+
+    /// Returns the next `n` pieces that will spawn, starting with [`GameState::next_pieces`].
+    ///
+    /// Pieces beyond the preview are generated from copies of the piece generator and random number
+    /// generator, so the game itself is not affected and will spawn exactly these pieces.
+    /// If the game uses [`RngSource::Thread`] its future cannot be predicted and at most the preview
+    /// is returned.
+    pub fn upcoming(&self, n: usize) -> Vec<Tetromino> {
+        let mut upcoming = self
+            .state
+            .next_pieces
+            .iter()
+            .copied()
+            .take(n)
+            .collect::<Vec<_>>();
+        if let GameRng::Pcg32(rng) = &self.rng {
+            let mut tetromino_generator = self.config.tetromino_generator.clone_with_state();
+            let mut rng = rng.clone();
+            upcoming.extend(
+                tetromino_generator
+                    .with_rng(&mut rng)
+                    .take(n.saturating_sub(upcoming.len())),
+            );
+        }
+        upcoming
+    }
+
     /// Immutable accessor for the current game configurations.
     pub fn config(&self) -> &GameConfig {
         &self.config
@@ -816,6 +2429,21 @@ impl Game {
         &mut self.config
     }
 
+    /// Sets [`GameConfig::preview_count`] and immediately adjusts [`GameState::next_pieces`] to it.
+    ///
+    /// Growing the preview draws new pieces from the generator, shrinking it drops the pieces at
+    /// the back of the preview. Pieces that remain in the preview are unaffected either way.
+    pub fn set_preview_count(&mut self, n: usize) {
+        self.config.preview_count = n;
+        self.state.next_pieces.truncate(n);
+        self.state.next_pieces.extend(
+            self.config
+                .tetromino_generator
+                .with_rng(&mut self.rng)
+                .take(n.saturating_sub(self.state.next_pieces.len())),
+        );
+    }
+
     /// Immutable accessor for the current game mode.
     pub fn mode(&self) -> &GameMode {
         &self.mode
@@ -826,6 +2454,89 @@ impl Game {
         &self.state
     }
 
+    /// Returns how much of the lock delay is left for the active piece while it rests on the
+    /// ground, from `1.0` (just landed) to `0.0` (about to lock).
+    ///
+    /// Returns `None` if there is no active piece, it is afloat, or no lock timer is running.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{ActivePiece, Game, GameMode, Orientation, Tetromino};
+    ///
+    /// let mut game = Game::new(GameMode::zen());
+    /// game.update(None, Duration::ZERO).unwrap();
+    /// let piece = ActivePiece { shape: Tetromino::O, orientation: Orientation::N, position: (4, 0) };
+    /// game.set_active_piece(piece).unwrap();
+    /// assert_eq!(game.lock_meter(), Some(1.0));
+    /// game.update(None, Duration::from_millis(250)).unwrap();
+    /// assert_eq!(game.lock_meter(), Some(0.5));
+    /// ```
+    pub fn lock_meter(&self) -> Option<f64> {
+        let (_, locking_data) = self.state.active_piece_data?;
+        if !locking_data.touches_ground {
+            return None;
+        }
+        let lock_time = self.state.events.get(&InternalEvent::LockTimer)?;
+        let lock_delay = self.lock_delay();
+        if lock_delay.is_zero() {
+            return Some(0.0);
+        }
+        let time_left = lock_time.saturating_sub(self.state.time);
+        Some((time_left.as_secs_f64() / lock_delay.as_secs_f64()).clamp(0.0, 1.0))
+    }
+
+    /// Iterates over all filled tiles on the board (excluding the active piece), row by row from the
+    /// bottom.
+    pub fn occupied_cells(&self) -> impl Iterator<Item = (Coord, TileTypeID)> + '_ {
+        self.state.board.iter().enumerate().flat_map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .filter_map(move |(x, tile)| tile.map(|tile_type_id| ((x, y), tile_type_id)))
+        })
+    }
+
+    /// Returns the height of the stack on the board, i.e. the index of the highest non-empty row
+    /// plus one, or `0` if the board is empty.
+    pub fn stack_height(&self) -> usize {
+        self.state
+            .board
+            .iter()
+            .rposition(|line| line.iter().any(Option::is_some))
+            .map_or(0, |y| y + 1)
+    }
+
+    /// Returns a copy of the board with the active piece (if any) drawn into it.
+    ///
+    /// The game's own [`GameState::board`] is left untouched.
+    pub fn render_board(&self) -> Board {
+        let mut board = self.state.board.clone();
+        if let Some((active_piece, _)) = self.state.active_piece_data {
+            for ((x, y), tile_type_id) in active_piece.tiles() {
+                board[y][x] = Some(tile_type_id);
+            }
+        }
+        board
+    }
+
+    /// Returns a copy of the board with the active piece (if any) and its ghost drawn into it.
+    ///
+    /// Ghost tiles use `ghost_tile` if given and the piece's own tile type otherwise; where the
+    /// ghost overlaps the active piece, the active piece is drawn.
+    pub fn render_board_with_ghost(&self, ghost_tile: Option<TileTypeID>) -> Board {
+        let mut board = self.state.board.clone();
+        if let Some((active_piece, _)) = self.state.active_piece_data {
+            for ((x, y), tile_type_id) in active_piece.ghost_tiles(&self.state.board) {
+                board[y][x] = Some(ghost_tile.unwrap_or(tile_type_id));
+            }
+            for ((x, y), tile_type_id) in active_piece.tiles() {
+                board[y][x] = Some(tile_type_id);
+            }
+        }
+        board
+    }
+
     /// Adds a 'game mod' that will get executed regularly before and after each [`InternalEvent`].
     ///
     /// # Safety
@@ -839,14 +2550,24 @@ impl Game {
         self.modifiers.push(game_mod)
     }
 
+    /// Adds an observer that will get called at the same points as game mods (c.f.
+    /// [`Game::add_modifier`]), but only gets to read the game.
+    ///
+    /// Besides the game's `GameConfig`, `GameMode` and `GameState` the observer receives all
+    /// [`Feedback`] emitted since it was last called, e.g. the feedback caused by an event at
+    /// [`ModifierPoint::AfterEvent`].
+    /// Observers are called after all game mods, and persist across [`Game::restart`].
+    pub fn add_observer(&mut self, observer: FnGameObserver) {
+        self.observers.push(observer)
+    }
+
     /// Updates the internal `self.state.end` state, checking whether any [`Limits`] have been reached.
     fn update_game_end(&mut self) {
         self.state.end = self.state.end.or_else(|| {
             [
-                self.mode
-                    .limits
-                    .time
-                    .and_then(|(win, dur)| (dur <= self.state.time).then_some(win)),
+                self.mode.limits.time.and_then(|(win, dur)| {
+                    (dur + self.state.time_bonus <= self.state.time).then_some(win)
+                }),
                 self.mode.limits.pieces.and_then(|(win, pcs)| {
                     (pcs <= self.state.pieces_played.iter().sum()).then_some(win)
                 }),
@@ -862,6 +2583,10 @@ impl Game {
                     .limits
                     .score
                     .and_then(|(win, pts)| (pts <= self.state.score).then_some(win)),
+                self.mode
+                    .limits
+                    .perfect_clears
+                    .and_then(|(win, pcs)| (pcs <= self.state.perfect_clears).then_some(win)),
             ]
             .into_iter()
             .find_map(|limit_reached| {
@@ -876,10 +2601,12 @@ impl Game {
         });
     }
 
-    /// Goes through all internal 'game mods' and applies them sequentially at the given [`ModifierPoint`].
+    /// Goes through all internal 'game mods' and applies them sequentially at the given [`ModifierPoint`],
+    /// then notifies all observers of the feedback after index `observed`, which is advanced accordingly.
     fn apply_modifiers(
         &mut self,
         feedback_events: &mut Vec<(GameTime, Feedback)>,
+        observed: &mut usize,
         modifier_point: &ModifierPoint,
     ) {
         for modify in &mut self.modifiers {
@@ -891,6 +2618,18 @@ impl Game {
                 modifier_point,
             );
         }
+        // NOTE: Modifiers may have removed feedback.
+        let new_feedback_events = feedback_events.get(*observed..).unwrap_or_default();
+        for observe in &mut self.observers {
+            observe(
+                &self.config,
+                &self.mode,
+                &self.state,
+                new_feedback_events,
+                modifier_point,
+            );
+        }
+        *observed = feedback_events.len();
     }
 
     /// The main function used to advance the game state.
@@ -903,14 +2642,46 @@ impl Game {
     ///
     /// Unless an error occurs, this function will return all [`FeedbackEvents`] caused between the
     /// previous and the current `update` call, in chronological order.
+    /// Feedback with identical times is returned in the order it was emitted, i.e. the order in
+    /// which the events causing it were handled.
+    ///
+    /// Updating to the current time (`update_time == game.state().time`) is allowed: any events
+    /// scheduled at exactly that time which have not been handled yet are handled once.
+    /// As every handled event is removed before returning, repeating the same call with `None` is a
+    /// no-op that returns no feedback.
+    ///
+    /// If [`GameConfig::max_catchup`] is set, the game advances at most that far past
+    /// `game.state().time`, even if `update_time` lies further in the future. Any new button state
+    /// is then applied at the time actually reached. The remaining time is simply caught up on by
+    /// subsequent calls, so a frontend that stalled stays responsive.
+    ///
+    /// While the game is paused (c.f. [`Game::pause`]) this does nothing and returns no feedback.
+    /// Once resumed, the time spent paused is subtracted from `update_time`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{Game, GameMode};
+    ///
+    /// let mut game = Game::new(GameMode::marathon());
+    /// // The first piece spawns at the very start of the game.
+    /// let feedback = game.update(None, Duration::ZERO).unwrap();
+    /// assert!(!feedback.is_empty());
+    /// let state = game.state().clone();
+    /// // Updating to the same time again does not handle the spawn a second time.
+    /// let feedback = game.update(None, Duration::ZERO).unwrap();
+    /// assert!(feedback.is_empty());
+    /// assert_eq!(game.state(), &state);
+    /// ```
     ///
     /// # Errors
     ///
     /// This function may error with:
     /// - [`GameUpdateError::GameEnded`] if `game.ended()` is `true`, indicating that no more updates
     ///   can change the game state, or
-    /// - [`GameUpdateError::DurationPassed`] if `update_time < game.state().time`, indicating that
-    ///   the requested update lies in the past.
+    /// - [`GameUpdateError::DurationPassed`] if `update_time` (minus any time spent paused) is less
+    ///   than `game.state().time`, indicating that the requested update lies in the past.
     pub fn update(
         &mut self,
         mut new_button_state: Option<ButtonsPressed>,
@@ -930,12 +2701,24 @@ impl Game {
             - try adding input events, goto *
             - else return immediately
          */
+        // Paused: nothing happens.
+        if self.is_paused() {
+            return Ok(Vec::new());
+        }
+        let update_time = update_time.saturating_sub(self.time_paused);
         // Invalid call: return immediately.
         if update_time < self.state.time {
             return Err(GameUpdateError::DurationPassed);
         }
+        // Do not advance further than allowed in one go.
+        let update_time = match self.config.max_catchup {
+            Some(max_catchup) => update_time.min(self.state.time.saturating_add(max_catchup)),
+            None => update_time,
+        };
         // NOTE: Returning an empty Vec is efficient because it won't even allocate (as by Rust API).
         let mut feedback_events = Vec::new();
+        // How many of the feedback events observers have seen already.
+        let mut observed = 0;
         if self.ended() {
             return Err(GameUpdateError::GameEnded);
         };
@@ -943,21 +2726,24 @@ impl Game {
         'event_simulation: loop {
             // Peek the next closest event.
             // SAFETY: `Game` invariants guarantee there's some event.
-            let (&event, &event_time) = self
-                .state
-                .events
-                .iter()
-                .min_by_key(|(&event, &event_time)| (event_time, event))
-                .unwrap();
+            let (event, event_time) = self.state.events.next_event().unwrap();
             // Next event within requested update time, handle event first.
             if event_time <= update_time {
-                self.apply_modifiers(&mut feedback_events, &ModifierPoint::BeforeEvent(event));
+                self.apply_modifiers(
+                    &mut feedback_events,
+                    &mut observed,
+                    &ModifierPoint::BeforeEvent(event),
+                );
                 // Remove next event and handle it.
-                self.state.events.remove_entry(&event);
+                self.state.events.remove(&event);
                 let new_feedback_events = self.handle_event(event, event_time);
                 self.state.time = event_time;
                 feedback_events.extend(new_feedback_events);
-                self.apply_modifiers(&mut feedback_events, &ModifierPoint::AfterEvent(event));
+                self.apply_modifiers(
+                    &mut feedback_events,
+                    &mut observed,
+                    &ModifierPoint::AfterEvent(event),
+                );
                 // Stop simulation early if event or modifier ended game.
                 self.update_game_end();
                 if self.ended() {
@@ -972,6 +2758,7 @@ impl Game {
                     if self.state.active_piece_data.is_some() {
                         self.apply_modifiers(
                             &mut feedback_events,
+                            &mut observed,
                             &ModifierPoint::BeforeButtonChange(
                                 self.state.buttons_pressed,
                                 buttons_pressed,
@@ -980,6 +2767,7 @@ impl Game {
                         self.add_input_events(buttons_pressed, update_time);
                         self.apply_modifiers(
                             &mut feedback_events,
+                            &mut observed,
                             &ModifierPoint::AfterButtonChange,
                         );
                     }
@@ -990,9 +2778,241 @@ impl Game {
                 }
             }
         }
+        debug_assert_eq!(self.validate_invariants(), Ok(()));
+        // Modifiers may add feedback at arbitrary times; keep emission order among equal times.
+        feedback_events.sort_by_key(|(feedback_time, _)| *feedback_time);
         Ok(feedback_events)
     }
 
+    /// Advances the game to the given time without changing any buttons, returning the feedback
+    /// caused.
+    ///
+    /// This is a thin, infallible wrapper over [`Game::update`] for simple tick loops, e.g. in
+    /// headless simulations: Times in the game's past are clamped to its current time, and once
+    /// the game has ended no feedback is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{Game, GameMode};
+    ///
+    /// let mut game = Game::new(GameMode::zen());
+    /// game.tick(Duration::from_secs(1));
+    /// // Going back in time does nothing instead of failing.
+    /// assert!(game.tick(Duration::ZERO).is_empty());
+    /// assert_eq!(game.state().time, Duration::from_secs(1));
+    /// ```
+    pub fn tick(&mut self, to: GameTime) -> FeedbackEvents {
+        let to = to.max(self.state.time.saturating_add(self.time_paused));
+        self.update(None, to).unwrap_or_default()
+    }
+
+    /// Checks whether the internal invariants of the game hold, returning a description of the
+    /// first violated invariant otherwise.
+    ///
+    /// The invariants are:
+    /// - The board always has the dimensions given by [`GameConfig::width`] and
+    ///   [`GameConfig::height`], with [`GameConfig::skyline`] not above its top.
+    /// - Until the game has ended there will always be more events.
+    /// - Until the game has ended, unhandled events do not lie in the past.
+    /// - An active piece in play always fits onto the board.
+    ///
+    /// This is checked after each [`Game::update`] in debug builds, and mainly serves to catch
+    /// state corruption by game modifiers (c.f. [`Game::add_modifier`]).
+    pub fn validate_invariants(&self) -> Result<(), String> {
+        let state = &self.state;
+        if state.board.len() != self.config.height {
+            return Err(format!(
+                "board height is {} instead of {}",
+                state.board.len(),
+                self.config.height
+            ));
+        }
+        if let Some((y, line)) = state
+            .board
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != self.config.width)
+        {
+            return Err(format!(
+                "line {y} has width {} instead of {}",
+                line.len(),
+                self.config.width
+            ));
+        }
+        if self.config.height < self.config.skyline {
+            return Err(format!(
+                "skyline {} lies above board height {}",
+                self.config.skyline, self.config.height
+            ));
+        }
+        if self.ended() {
+            return Ok(());
+        }
+        if state.events.is_empty() {
+            return Err("game has not ended but no events are scheduled".to_string());
+        }
+        if let Some((event, event_time)) = state.events.next_event() {
+            if event_time < state.time {
+                return Err(format!(
+                    "event {event:?} scheduled at {event_time:?} lies before current game time {:?}",
+                    state.time
+                ));
+            }
+        }
+        if let Some((active_piece, _)) = state.active_piece_data {
+            if !active_piece.fits(&state.board) {
+                return Err(format!(
+                    "active piece {active_piece:?} does not fit onto the board"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether a perfect clear (an entirely empty board) can still be reached by placing at
+    /// most `within_pieces` of the known upcoming pieces (the active piece, followed by
+    /// [`GameState::next_pieces`]).
+    ///
+    /// Only placements reachable by dropping a piece straight down from above the stack are
+    /// considered, i.e. no spins or tucks. The search tries every such placement of each piece in
+    /// turn, so its cost grows exponentially with `within_pieces`; it is meant to look only a few
+    /// pieces ahead, and never looks further than the preview (c.f. [`GameConfig::preview_count`]).
+    pub fn perfect_clear_possible(&self, within_pieces: usize) -> bool {
+        let pieces = self
+            .state
+            .active_piece_data
+            .map(|(active_piece, _)| active_piece.shape)
+            .into_iter()
+            .chain(self.state.next_pieces.iter().copied())
+            .take(within_pieces)
+            .collect::<Vec<_>>();
+        self.perfect_clear_search(&self.state.board, &pieces)
+    }
+
+    /// Depth-first search for a sequence of straight drop placements of the given pieces that
+    /// empties the board.
+    fn perfect_clear_search(&self, board: &Board, pieces: &[Tetromino]) -> bool {
+        let filled_cells = board.iter().flatten().filter(|cell| cell.is_some()).count();
+        if filled_cells == 0 {
+            return true;
+        }
+        // All tiles must eventually be cleared, which takes at least one line per row of the stack.
+        let stack_height = board
+            .iter()
+            .rposition(|line| line.iter().any(|cell| cell.is_some()))
+            .map_or(0, |y| y + 1);
+        let clear_reachable = (0..=pieces.len()).any(|n| {
+            let total_cells = filled_cells + 4 * n;
+            let width = self.config.width;
+            total_cells % width == 0 && total_cells >= width * stack_height
+        });
+        let Some((&shape, pieces_left)) = pieces.split_first() else {
+            return false;
+        };
+        if !clear_reachable {
+            return false;
+        }
+        self.drop_placements(board, shape).any(|piece| {
+            self.perfect_clear_search(&self.lock_and_clear(board, &piece), pieces_left)
+        })
+    }
+
+    /// Returns where the active piece would land if it were dropped straight down ('ghost piece').
+    ///
+    /// Returns `None` while there is no active piece, e.g. during line clear or appearance delay.
+    /// The ghost is recomputed against the current board on each call, so it is valid to call this
+    /// every frame.
+    pub fn ghost_piece(&self) -> Option<ActivePiece> {
+        let (active_piece, _) = self.state.active_piece_data?;
+        Some(active_piece.well_piece(&self.state.board))
+    }
+
+    /// Returns how many rows the active piece, if any, would fall if it were dropped straight down.
+    pub fn drop_distance(&self) -> Option<usize> {
+        let (active_piece, _) = self.state.active_piece_data?;
+        let well_piece = active_piece.well_piece(&self.state.board);
+        Some(active_piece.position.1 - well_piece.position.1)
+    }
+
+    /// Returns how far the active piece has progressed through its current gravity interval, from
+    /// `0.0` (just fell) to `1.0` (about to fall), e.g. to smoothly render it between rows.
+    ///
+    /// Returns `None` if there is no active piece or it rests on the ground.
+    pub fn fall_progress(&self) -> Option<f64> {
+        let (active_piece, _) = self.state.active_piece_data?;
+        active_piece.fits_at(&self.state.board, (0, -1))?;
+        let fall_time = self.state.events.get(&InternalEvent::Fall)?;
+        let drop_delay = self.drop_delay(self.held_soft_drop_factor());
+        if drop_delay.is_zero() {
+            return Some(1.0);
+        }
+        let time_left = fall_time.saturating_sub(self.state.time);
+        Some((1.0 - time_left.as_secs_f64() / drop_delay.as_secs_f64()).clamp(0.0, 1.0))
+    }
+
+    /// Suggests where to place the active piece, if any, based on a simple heuristic.
+    ///
+    /// Only placements reachable by dropping a piece straight down from above the stack are
+    /// considered. The suggestion minimizes the number of holes left in the stack, then the
+    /// bumpiness of the stack surface, then the height at which the piece is placed.
+    pub fn suggest_placement(&self) -> Option<ActivePiece> {
+        let (active_piece, _) = self.state.active_piece_data?;
+        let board = &self.state.board;
+        self.drop_placements(board, active_piece.shape)
+            .min_by_key(|piece| {
+                let next_board = self.lock_and_clear(board, piece);
+                (
+                    analysis::count_holes(&next_board),
+                    analysis::aggregate_bumpiness(&next_board),
+                    piece.position.1,
+                )
+            })
+    }
+
+    /// Returns all distinct placements of a piece reachable by dropping it straight down from above
+    /// the skyline.
+    fn drop_placements<'a>(
+        &self,
+        board: &'a Board,
+        shape: Tetromino,
+    ) -> impl Iterator<Item = ActivePiece> + 'a {
+        let (width, skyline) = (self.config.width, self.config.skyline);
+        // Orientations which only differ by position are redundant.
+        let orientations = match shape {
+            Tetromino::O => &[Orientation::N][..],
+            Tetromino::I | Tetromino::S | Tetromino::Z => &[Orientation::N, Orientation::E][..],
+            _ => &[
+                Orientation::N,
+                Orientation::E,
+                Orientation::S,
+                Orientation::W,
+            ][..],
+        };
+        orientations.iter().flat_map(move |&orientation| {
+            (0..width).filter_map(move |x| {
+                let piece = ActivePiece {
+                    shape,
+                    orientation,
+                    position: (x, skyline),
+                };
+                piece.fits(board).then(|| piece.well_piece(board))
+            })
+        })
+    }
+
+    /// Returns the board resulting from locking the given piece and removing any full lines.
+    fn lock_and_clear(&self, board: &Board, piece: &ActivePiece) -> Board {
+        let mut board = board.clone();
+        for ((x, y), tile_type_id) in piece.tiles() {
+            board[y][x] = Some(tile_type_id);
+        }
+        board.retain(|line| line.iter().any(|cell| cell.is_none()));
+        board.resize(self.config.height, self.config.empty_line());
+        board
+    }
+
     /// Computes and adds to the internal event queue any relevant [`InternalEvent`]s caused by the
     /// player in form of a change of button states.
     fn add_input_events(&mut self, next_buttons_pressed: ButtonsPressed, update_time: GameTime) {
@@ -1000,6 +3020,17 @@ impl Game {
         let [mL0, mR0, rL0, rR0, rA0, dS0, dH0, dC0] = self.state.buttons_pressed;
         #[allow(non_snake_case)]
         let [mL1, mR1, rL1, rR1, rA1, dS1, dH1, dC1] = next_buttons_pressed;
+        // Tally newly pressed buttons.
+        let new_moves = u32::from(!mL0 && mL1) + u32::from(!mR0 && mR1);
+        let new_rotations =
+            u32::from(!rL0 && rL1) + u32::from(!rR0 && rR1) + u32::from(!rA0 && rA1);
+        self.state.piece_inputs += new_moves + new_rotations;
+        let counts = &mut self.state.input_counts;
+        counts.moves += new_moves;
+        counts.rotations += new_rotations;
+        counts.soft_drops += u32::from(!dS0 && dS1);
+        counts.hard_drops += u32::from(!dH0 && dH1);
+        counts.sonic_drops += u32::from(!dC0 && dC1);
         /*
         Table:                                 Karnaugh map:
         | mL0 mR0 mL1 mR1                      |           !mL1 !mL1  mL1  mL1
@@ -1020,6 +3051,10 @@ impl Game {
         |  1   1   1   0  :  move, move (ARR)
         |  1   1   1   1  :  -
         */
+        // Any change to the move buttons starts charging anew.
+        if (mL0, mR0) != (mL1, mR1) {
+            self.state.das_charged = false;
+        }
         // No buttons pressed -> one button pressed, add initial move.
         if (!mL0 && !mR0) && (mL1 != mR1) {
             self.state
@@ -1079,10 +3114,9 @@ impl Game {
                 .insert(InternalEvent::SoftDrop, update_time);
         // Soft drop button released: Reset fall timer.
         } else if dS0 && !dS1 {
-            self.state.events.insert(
-                InternalEvent::Fall,
-                update_time + Self::drop_delay(self.state.level, None),
-            );
+            self.state
+                .events
+                .insert(InternalEvent::Fall, update_time + self.drop_delay(None));
         }
         // Sonic drop button pressed
         if !dC0 && dC1 {
@@ -1098,14 +3132,240 @@ impl Game {
         }
     }
 
+    /// Applies the configured [`TopoutPolicy`] by removing the topmost non-empty rows of the stack.
+    ///
+    /// Returns whether any rows were removed, i.e. whether the game may continue.
+    fn clear_top_rows_on_topout(&mut self) -> bool {
+        let TopoutPolicy::ClearTopRows(n_rows) = self.config.topout_policy else {
+            return false;
+        };
+        let mut n_removed = 0;
+        while n_removed < n_rows {
+            let Some(y) = self
+                .state
+                .board
+                .iter()
+                .rposition(|line| line.iter().any(|mino| mino.is_some()))
+            else {
+                break;
+            };
+            self.state.board.remove(y);
+            self.state.board.push(self.config.empty_line());
+            n_removed += 1;
+        }
+        n_removed > 0
+    }
+
+    /// Checks whether the upcoming piece, or any piece if none is previewed, would overlap the
+    /// stack at its spawn position.
+    fn spawn_blocked(&self) -> bool {
+        let blocked = |shape| !self.position_tetromino(shape).fits(&self.state.board);
+        match self.state.next_pieces.front() {
+            Some(&shape) => blocked(shape),
+            None => (0..7)
+                .filter_map(|i| Tetromino::try_from(i).ok())
+                .any(blocked),
+        }
+    }
+
+    /// Returns the least number of move and rotation inputs needed to drop a freshly spawned piece
+    /// into the given spot, or `None` if it cannot be reached by moving and rotating at spawn height.
+    ///
+    /// Holding a 'move' button to shift the piece all the way to a side counts as one input.
+    fn finesse_optimum(&self, target: &ActivePiece) -> Option<u32> {
+        let board = &self.state.board;
+        // Orientations of symmetric pieces may cover the same cells, so compare tiles.
+        let cells = |piece: &ActivePiece| {
+            let mut cells = piece.tiles().map(|(coord, _)| coord);
+            cells.sort_unstable();
+            cells
+        };
+        let target_cells = cells(target);
+        let spawn_piece = self.position_tetromino(target.shape);
+        if !spawn_piece.fits(board) {
+            return None;
+        }
+        let mut seen = HashSet::from([spawn_piece]);
+        let mut queue = VecDeque::from([(spawn_piece, 0)]);
+        while let Some((piece, inputs)) = queue.pop_front() {
+            if cells(&piece.well_piece(board)) == target_cells {
+                return Some(inputs);
+            }
+            let shift = |dx| {
+                let mut shifted = piece.fits_at(board, (dx, 0))?;
+                while let Some(next) = shifted.fits_at(board, (dx, 0)) {
+                    shifted = next;
+                }
+                Some(shifted)
+            };
+            let next_pieces = [
+                piece.fits_at(board, (-1, 0)),
+                piece.fits_at(board, (1, 0)),
+                shift(-1),
+                shift(1),
+                self.config.rotation_system.rotate(&piece, board, -1),
+                self.config.rotation_system.rotate(&piece, board, 1),
+                self.config.rotation_system.rotate(&piece, board, 2),
+            ];
+            for next_piece in next_pieces.into_iter().flatten() {
+                if seen.insert(next_piece) {
+                    queue.push_back((next_piece, inputs + 1));
+                }
+            }
+        }
+        None
+    }
+
+    /// Lets every group of (orthogonally) connected tiles fall until it rests on the floor or on
+    /// another group, c.f. [`LineClearGravity::Cascade`].
+    ///
+    /// Returns whether any tiles moved.
+    fn cascade_tiles(&mut self) -> bool {
+        let (width, height) = (self.config.width, self.config.height);
+        // Label connected tile groups.
+        let mut group_of = vec![vec![None; width]; height];
+        let mut groups = Vec::<Vec<Coord>>::new();
+        for y in 0..height {
+            for x in 0..width {
+                if self.state.board[y][x].is_none() || group_of[y][x].is_some() {
+                    continue;
+                }
+                let id = groups.len();
+                let mut group = Vec::new();
+                let mut stack = vec![(x, y)];
+                group_of[y][x] = Some(id);
+                while let Some((x, y)) = stack.pop() {
+                    group.push((x, y));
+                    let neighbors = [
+                        (x.wrapping_sub(1), y),
+                        (x + 1, y),
+                        (x, y.wrapping_sub(1)),
+                        (x, y + 1),
+                    ];
+                    for (x, y) in neighbors {
+                        if x < width
+                            && y < height
+                            && self.state.board[y][x].is_some()
+                            && group_of[y][x].is_none()
+                        {
+                            group_of[y][x] = Some(id);
+                            stack.push((x, y));
+                        }
+                    }
+                }
+                groups.push(group);
+            }
+        }
+        // Move unsupported groups down one row at a time until nothing moves anymore.
+        let mut any_moved = false;
+        loop {
+            let mut moved = false;
+            for (id, group) in groups.iter_mut().enumerate() {
+                let supported = group.iter().any(|&(x, y)| {
+                    y == 0 || group_of[y - 1][x].is_some_and(|other_id| other_id != id)
+                });
+                if supported {
+                    continue;
+                }
+                let tiles = group
+                    .iter()
+                    .map(|&(x, y)| {
+                        group_of[y][x] = None;
+                        self.state.board[y][x].take()
+                    })
+                    .collect::<Vec<_>>();
+                for ((x, y), tile) in group.iter_mut().zip(tiles) {
+                    *y -= 1;
+                    self.state.board[*y][*x] = tile;
+                    group_of[*y][*x] = Some(id);
+                }
+                moved = true;
+            }
+            if !moved {
+                break any_moved;
+            }
+            any_moved = true;
+        }
+    }
+
+    /// Determines how a piece about to be locked down was spun into place.
+    ///
+    /// Pieces must have been rotated last. T-pieces then use the 'three corner' rule, other pieces
+    /// count as spun if they cannot move up, left or right.
+    fn spin_kind(&self, piece: &ActivePiece, last_kick: Option<KickInfo>) -> SpinKind {
+        let board = &self.state.board;
+        // Only pieces rotated as their last action can have been spun.
+        let Some(kick) = last_kick else {
+            return SpinKind::None;
+        };
+        if piece.shape != Tetromino::T {
+            let immobile = [(0, 1), (-1, 0), (1, 0)]
+                .iter()
+                .all(|&offset| piece.fits_at(board, offset).is_none());
+            return if immobile {
+                SpinKind::Immobile
+            } else {
+                SpinKind::None
+            };
+        }
+        use Orientation::*;
+        let (x, y) = piece.position;
+        // Center of the T and its diagonal corners, the two it points towards first.
+        #[rustfmt::skip]
+        let (center, corners) = match piece.orientation {
+            N => ((x + 1, y    ), [(-1, 1), ( 1, 1), (-1,-1), ( 1,-1)]),
+            E => ((x    , y + 1), [( 1, 1), ( 1,-1), (-1, 1), (-1,-1)]),
+            S => ((x + 1, y + 1), [(-1,-1), ( 1,-1), (-1, 1), ( 1, 1)]),
+            W => ((x + 1, y + 1), [(-1, 1), (-1,-1), ( 1, 1), ( 1,-1)]),
+        };
+        // Out-of-bounds corners count as occupied.
+        let occupied = corners.map(|offset| {
+            add(center, offset)
+                .and_then(|(x, y)| board.get(y).and_then(|line| line.get(x)))
+                .map_or(true, |cell| cell.is_some())
+        });
+        if occupied.iter().filter(|&&corner| corner).count() < 3 {
+            SpinKind::None
+        // NOTE: The last Super kick is the only one to turn a mini into a full spin (as in 'TST twist').
+        } else if (occupied[0] && occupied[1])
+            || (self.config.rotation_system == RotationSystem::Super && kick.index == 4)
+        {
+            SpinKind::Full
+        } else {
+            SpinKind::Mini
+        }
+    }
+
     /// Given a tetromino variant to be spawned onto the board, returns the correct initial state of
     /// [`ActivePiece`].
-    fn position_tetromino(shape: Tetromino) -> ActivePiece {
-        let pos = match shape {
-            Tetromino::O => (4, 20),
-            _ => (3, 20),
-        };
+    fn position_tetromino(&self, shape: Tetromino) -> ActivePiece {
         let orientation = Orientation::N;
+        // Center the piece horizontally, rounding to the left.
+        let shape_width = shape
+            .minos(orientation)
+            .iter()
+            .map(|&(x, _)| x + 1)
+            .max()
+            .unwrap_or(0);
+        let x = self.config.width.saturating_sub(shape_width) / 2;
+        let y = match self.config.spawn_row {
+            SpawnRow::Skyline => self.config.skyline,
+            SpawnRow::Fixed(y) => y,
+            SpawnRow::AboveStack => {
+                // Find the lowest row from the skyline upwards at which the piece fits, if any.
+                (self.config.skyline..self.config.height)
+                    .find(|&y| {
+                        ActivePiece {
+                            shape,
+                            orientation,
+                            position: (x, y),
+                        }
+                        .fits(&self.state.board)
+                    })
+                    .unwrap_or(self.config.skyline)
+            }
+        };
+        let pos = (x, y);
         /* NOTE: Unused spawn positions/orientations. While nice and symmetrical :): also unusual.
         let (orientation, pos) = match shape {
             Tetromino::O => (Orientation::N, (4, 20)),
@@ -1133,6 +3393,7 @@ impl Game {
         let mut feedback_events = Vec::new();
         let prev_piece_data = self.state.active_piece_data;
         let prev_piece = prev_piece_data.unzip().0;
+        let mut rotation_kick = None;
         let next_piece = match event {
             // We generate a new piece above the skyline, and immediately queue a fall event for it.
             InternalEvent::Spawn => {
@@ -1157,40 +3418,86 @@ impl Game {
                                 .saturating_sub(self.state.next_pieces.len()),
                         ),
                 );
-                let next_piece = Self::position_tetromino(tetromino);
-                // Newly spawned piece conflicts with board - Game over.
-                if !next_piece.fits(&self.state.board) {
+                // Newly spawned piece conflicts with board - Try further up, make room, or game over.
+                let spawn_retry_rows = self.config.spawn_retry_rows;
+                let raise = |piece: ActivePiece, board: &Board| {
+                    (0..=spawn_retry_rows)
+                        .find_map(|dy| piece.fits_at(board, (0, isize::try_from(dy).ok()?)))
+                };
+                let mut spawned_piece =
+                    raise(self.position_tetromino(tetromino), &self.state.board);
+                if spawned_piece.is_none() && self.clear_top_rows_on_topout() {
+                    spawned_piece = raise(self.position_tetromino(tetromino), &self.state.board);
+                }
+                let Some(next_piece) = spawned_piece else {
                     self.state.end = Some(Err(GameOver::BlockOut));
                     return feedback_events;
+                };
+                for count in &mut self.state.spawns_since_seen {
+                    *count = count.saturating_add(1);
                 }
-                let mut turns = 0;
-                if self.state.buttons_pressed[Button::RotateRight] {
-                    turns += 1;
-                }
-                if self.state.buttons_pressed[Button::RotateAround] {
-                    turns += 2;
-                }
-                if self.state.buttons_pressed[Button::RotateLeft] {
-                    turns -= 1;
-                }
-                if turns != 0 {
+                self.state.spawns_since_seen[tetromino] = 0;
+                let initial_turns = if let Some(turns) = self.state.queued_initial_rotation.take() {
+                    turns
+                } else if self.config.initial_rotation {
+                    let mut turns = 0;
+                    if self.state.buttons_pressed[Button::RotateRight] {
+                        turns += 1;
+                    }
+                    if self.state.buttons_pressed[Button::RotateAround] {
+                        turns += 2;
+                    }
+                    if self.state.buttons_pressed[Button::RotateLeft] {
+                        turns -= 1;
+                    }
+                    turns
+                } else {
+                    0
+                };
+                if initial_turns != 0 {
                     self.state
                         .events
-                        .insert(InternalEvent::Rotate(turns), event_time);
+                        .insert(InternalEvent::Rotate(initial_turns), event_time);
                 }
                 self.state.events.insert(InternalEvent::Fall, event_time);
+                // The newly spawned piece immediately falls by one if possible (all the way down
+                // with 20G), and only then does its initial rotation.
+                let twenty_g = self.drop_delay(None).is_zero();
+                let fall = |piece: ActivePiece| {
+                    if twenty_g {
+                        piece.well_piece(&self.state.board)
+                    } else {
+                        piece.fits_at(&self.state.board, (0, -1)).unwrap_or(piece)
+                    }
+                };
+                let mut first_rest = fall(next_piece);
+                if initial_turns != 0 {
+                    first_rest = self.rotate_piece(first_rest, initial_turns).0;
+                    if twenty_g {
+                        first_rest = fall(first_rest);
+                    }
+                }
+                feedback_events.push((
+                    event_time,
+                    Feedback::Spawn {
+                        piece: next_piece,
+                        first_rest,
+                    },
+                ));
                 Some(next_piece)
             }
             InternalEvent::Rotate(turns) => {
                 let prev_piece = prev_piece.expect("rotate event but no active piece");
-                self.config
-                    .rotation_system
-                    .rotate(&prev_piece, &self.state.board, turns)
-                    .or(Some(prev_piece))
+                let (next_piece, kick) = self.rotate_piece(prev_piece, turns);
+                rotation_kick = kick;
+                Some(next_piece)
             }
             InternalEvent::MoveSlow | InternalEvent::MoveFast => {
                 // Handle move attempt and auto repeat move.
                 let prev_piece = prev_piece.expect("move event but no active piece");
+                if event == InternalEvent::MoveFast {
+                    self.state.das_charged = true;
+                }
                 #[rustfmt::skip]
                 let mut dx = 0;
                 if self.state.buttons_pressed[Button::MoveLeft] {
@@ -1206,15 +3513,19 @@ impl Game {
                         } else {
                             self.config.auto_repeat_rate
                         }
-                        .min(
-                            Self::lock_delay(&self.state.level)
-                                .saturating_sub(Duration::from_millis(1)),
-                        );
+                        .min(self.lock_delay().saturating_sub(Duration::from_millis(1)));
                         self.state
                             .events
                             .insert(InternalEvent::MoveFast, event_time + move_delay);
                         next_piece
                     } else {
+                        // Keep charging while pushing against an obstacle.
+                        if event == InternalEvent::MoveSlow {
+                            self.state.events.insert(
+                                InternalEvent::MoveFast,
+                                event_time + self.config.delayed_auto_shift,
+                            );
+                        }
                         prev_piece
                     },
                 )
@@ -1225,15 +3536,27 @@ impl Game {
                 Some(
                     if let Some(dropped_piece) = prev_piece.fits_at(&self.state.board, (0, -1)) {
                         // Drop delay is possibly faster due to soft drop button pressed.
-                        let soft_drop = self.state.buttons_pressed[Button::DropSoft]
-                            .then_some(self.config.soft_drop_factor);
-                        let drop_delay = Self::drop_delay(self.state.level, soft_drop);
+                        let soft_drop = self.held_soft_drop_factor();
+                        if soft_drop.is_some() {
+                            self.add_drop_score(1, 1);
+                        }
+                        let drop_delay = self.drop_delay(soft_drop);
                         self.state
                             .events
                             .insert(InternalEvent::Fall, event_time + drop_delay);
+                        // Piece landed and should lock without delay.
+                        if self.config.instant_lock
+                            && dropped_piece.fits_at(&self.state.board, (0, -1)).is_none()
+                        {
+                            self.state.events.insert(InternalEvent::Lock, event_time);
+                        }
                         dropped_piece
                     } else {
                         // Otherwise piece could not move down.
+                        // Lock immediately if pieces should lock without delay.
+                        if self.config.instant_lock {
+                            self.state.events.insert(InternalEvent::Lock, event_time);
+                        }
                         prev_piece
                     },
                 )
@@ -1243,9 +3566,9 @@ impl Game {
                 // Try to drop active piece down by one, and queue next fall event.
                 Some(
                     if let Some(dropped_piece) = prev_piece.fits_at(&self.state.board, (0, -1)) {
-                        let soft_drop = self.state.buttons_pressed[Button::DropSoft]
-                            .then_some(self.config.soft_drop_factor);
-                        let drop_delay = Self::drop_delay(self.state.level, soft_drop);
+                        self.add_drop_score(1, 1);
+                        let soft_drop = self.held_soft_drop_factor();
+                        let drop_delay = self.drop_delay(soft_drop);
                         self.state
                             .events
                             .insert(InternalEvent::Fall, event_time + drop_delay);
@@ -1263,13 +3586,17 @@ impl Game {
             InternalEvent::SonicDrop => {
                 let prev_piece = prev_piece.expect("sonicdrop event but no active piece");
                 // Move piece all the way down and nothing more.
-                Some(prev_piece.well_piece(&self.state.board))
+                let dropped_piece = prev_piece.well_piece(&self.state.board);
+                self.add_drop_score(prev_piece.position.1 - dropped_piece.position.1, 1);
+                feedback_events.push((event_time, Feedback::QuickDrop(prev_piece, dropped_piece)));
+                Some(dropped_piece)
             }
             InternalEvent::HardDrop => {
                 let prev_piece = prev_piece.expect("harddrop event but no active piece");
                 // Move piece all the way down.
                 let dropped_piece = prev_piece.well_piece(&self.state.board);
-                feedback_events.push((event_time, Feedback::HardDrop(prev_piece, dropped_piece)));
+                self.add_drop_score(prev_piece.position.1 - dropped_piece.position.1, 2);
+                feedback_events.push((event_time, Feedback::QuickDrop(prev_piece, dropped_piece)));
                 self.state.events.insert(
                     InternalEvent::LockTimer,
                     event_time + self.config.hard_drop_delay,
@@ -1280,28 +3607,83 @@ impl Game {
                 self.state.events.insert(InternalEvent::Lock, event_time);
                 prev_piece
             }
+            InternalEvent::Countdown => {
+                if let Some(spawn_time) = self.state.events.get(&InternalEvent::Spawn) {
+                    let remaining = spawn_time.saturating_sub(event_time);
+                    feedback_events.push((event_time, Feedback::Countdown(remaining)));
+                    // Tick once every second until the first spawn.
+                    let next_tick = event_time + Duration::from_secs(1);
+                    if next_tick < spawn_time {
+                        self.state
+                            .events
+                            .insert(InternalEvent::Countdown, next_tick);
+                    }
+                }
+                prev_piece
+            }
             InternalEvent::Lock => {
                 let prev_piece = prev_piece.expect("lock event but no active piece");
                 feedback_events.push((event_time, Feedback::PieceLocked(prev_piece)));
-                // Attempt to lock active piece fully above skyline - Game over.
-                if prev_piece
-                    .tiles()
-                    .iter()
-                    .all(|((_, y), _)| *y >= Self::SKYLINE)
-                {
+                // Attempt to lock active piece above skyline - Game over.
+                let above_skyline = |((_, y), _): &(Coord, TileTypeID)| *y >= self.config.skyline;
+                let locks_out = match self.config.topout_rule {
+                    TopoutRule::AllAboveSkyline => prev_piece.tiles().iter().all(above_skyline),
+                    TopoutRule::AnyAboveSkyline => prev_piece.tiles().iter().any(above_skyline),
+                };
+                if locks_out {
+                    // Discard the piece and spawn the next one if we are allowed to make room.
+                    if self.clear_top_rows_on_topout() {
+                        self.state.active_piece_data = None;
+                        self.state.events.clear();
+                        self.state.events.insert(
+                            InternalEvent::Spawn,
+                            event_time + self.config.appearance_delay,
+                        );
+                        return feedback_events;
+                    }
                     self.state.end = Some(Err(GameOver::LockOut));
                     return feedback_events;
                 }
                 self.state.pieces_played[prev_piece.shape] += 1;
+                // Check whether the piece could have been placed with fewer inputs.
+                let inputs = std::mem::take(&mut self.state.piece_inputs);
+                // NOTE: A piece placed without any inputs cannot be placed with fewer.
+                if let Some(optimal) = (inputs > 0)
+                    .then(|| self.finesse_optimum(&prev_piece))
+                    .flatten()
+                {
+                    if optimal < inputs {
+                        self.state.finesse_faults += 1;
+                        feedback_events.push((
+                            event_time,
+                            Feedback::FinesseFault {
+                                piece: prev_piece,
+                                inputs,
+                                optimal,
+                            },
+                        ));
+                    }
+                }
                 // Pre-save whether piece was spun into lock position.
-                let spin = prev_piece.fits_at(&self.state.board, (0, 1)).is_none();
+                let last_kick =
+                    prev_piece_data.and_then(|(_, locking_data)| locking_data.last_kick);
+                let spin = self.spin_kind(&prev_piece, last_kick);
                 // Locking.
+                let board_before = self
+                    .config
+                    .emit_board_changes
+                    .then(|| self.state.board.clone());
                 for ((x, y), tile_type_id) in prev_piece.tiles() {
                     self.state.board[y][x] = Some(tile_type_id);
                 }
+                self.state.max_stack_height = self.state.max_stack_height.max(self.stack_height());
+                if let Some(before) = board_before {
+                    let after = self.state.board.clone();
+                    feedback_events.push((event_time, Feedback::BoardChanged { before, after }));
+                }
                 // Handle line clear counting for score (only do actual clearing in LineClear).
                 let mut lines_cleared = Vec::<usize>::with_capacity(4);
-                for y in (0..Self::HEIGHT).rev() {
+                for y in (0..self.config.height).rev() {
                     if self.state.board[y].iter().all(|mino| mino.is_some()) {
                         lines_cleared.push(y);
                     }
@@ -1309,24 +3691,32 @@ impl Game {
                 let n_lines_cleared = u32::try_from(lines_cleared.len()).unwrap();
                 if n_lines_cleared > 0 {
                     // Add score bonus.
+                    // NOTE: The full lines are still on the board, so all other lines must be empty.
                     let perfect_clear = self
                         .state
                         .board
                         .iter()
-                        .all(|line| line.iter().all(|tile| tile.is_none()));
+                        .enumerate()
+                        .filter(|(y, _)| !lines_cleared.contains(y))
+                        .all(|(_, line)| line.iter().all(|tile| tile.is_none()));
                     self.state.consecutive_line_clears += 1;
-                    let special_clear = n_lines_cleared >= 4 || spin || perfect_clear;
+                    let special_clear =
+                        n_lines_cleared >= 4 || spin != SpinKind::None || perfect_clear;
                     if special_clear {
                         self.state.back_to_back_special_clears += 1;
                     } else {
                         self.state.back_to_back_special_clears = 0;
                     }
-                    let score_bonus = 10
-                        * (n_lines_cleared + self.state.consecutive_line_clears - 1).pow(2)
-                        * self.state.back_to_back_special_clears.max(1)
-                        * if spin { 4 } else { 1 }
-                        * if perfect_clear { 100 } else { 1 };
-                    self.state.score += score_bonus;
+                    let score_event = ScoreEvent {
+                        lineclears: n_lines_cleared,
+                        spin,
+                        perfect_clear,
+                        combo: self.state.consecutive_line_clears,
+                        back_to_back: self.state.back_to_back_special_clears,
+                        level: self.state.level,
+                    };
+                    let score_bonus = (self.config.scoring)(&score_event);
+                    self.state.score = self.state.score.saturating_add(score_bonus);
                     let yippie = Feedback::Accolade {
                         score_bonus,
                         shape: prev_piece.shape,
@@ -1337,13 +3727,75 @@ impl Game {
                         back_to_back: self.state.back_to_back_special_clears,
                     };
                     feedback_events.push((event_time, yippie));
+                    if self.mode.limits.time_bonus {
+                        let time_bonus = score_event.default_time_bonus();
+                        self.state.time_bonus += time_bonus;
+                        feedback_events.push((event_time, Feedback::TimeBonus(time_bonus)));
+                    }
+                    if perfect_clear {
+                        self.state.perfect_clears += 1;
+                        feedback_events.push((
+                            event_time,
+                            Feedback::PerfectClear {
+                                lines: n_lines_cleared,
+                            },
+                        ));
+                    }
+                    // Each surviving row shifts down by the number of cleared rows below it.
+                    let mut line_shifts = Vec::new();
+                    let mut shift = 0;
+                    for y in 0..self.config.height {
+                        if lines_cleared.contains(&y) {
+                            shift += 1;
+                        } else if shift > 0 {
+                            line_shifts.push((y, shift));
+                        }
+                    }
                     feedback_events.push((
                         event_time,
                         Feedback::LineClears(lines_cleared, self.config.line_clear_delay),
                     ));
-                } else {
+                    feedback_events.push((event_time, Feedback::LineShifts(line_shifts)));
+                } else if !(spin != SpinKind::None
+                    && self.config.combo_rule == ComboRule::KeepOnSpin)
+                {
                     self.state.consecutive_line_clears = 0;
                 }
+                // Insert queued garbage if the piece did not clear any lines.
+                if n_lines_cleared == 0 && !self.state.queued_garbage.is_empty() {
+                    let hole_columns = std::mem::take(&mut self.state.queued_garbage);
+                    let board_before = self
+                        .config
+                        .emit_board_changes
+                        .then(|| self.state.board.clone());
+                    let mut overflowed = false;
+                    // Earlier queued lines end up above later ones.
+                    for &hole_column in &hole_columns {
+                        let mut line =
+                            vec![Some(Self::GARBAGE_TILE); self.config.width].into_boxed_slice();
+                        line[hole_column] = None;
+                        self.state.board.insert(0, line);
+                        // SAFETY: We just inserted a line.
+                        let top_line = self.state.board.pop().unwrap();
+                        overflowed |= top_line.iter().any(|mino| mino.is_some());
+                    }
+                    feedback_events.push((event_time, Feedback::GarbageInserted(hole_columns)));
+                    if let Some(before) = board_before {
+                        let after = self.state.board.clone();
+                        feedback_events
+                            .push((event_time, Feedback::BoardChanged { before, after }));
+                    }
+                    let above_skyline = self.state.board[self.config.skyline..]
+                        .iter()
+                        .any(|line| line.iter().any(|mino| mino.is_some()));
+                    // Tiles above the skyline only end the game if they are in the way of spawning.
+                    if (overflowed || above_skyline && self.spawn_blocked())
+                        && !self.clear_top_rows_on_topout()
+                    {
+                        self.state.end = Some(Err(GameOver::LockOut));
+                        return feedback_events;
+                    }
+                }
                 // Clear all events and only put in line clear / appearance delay.
                 self.state.events.clear();
                 if n_lines_cleared > 0 {
@@ -1360,22 +3812,60 @@ impl Game {
                 None
             }
             InternalEvent::LineClear => {
-                for y in (0..Self::HEIGHT).rev() {
+                let lines_cleared_before = self.state.lines_cleared;
+                let board_before = self
+                    .config
+                    .emit_board_changes
+                    .then(|| self.state.board.clone());
+                for y in (0..self.config.height).rev() {
                     // Full line: move it to the cleared lines storage and push an empty line to the board.
                     if self.state.board[y].iter().all(|mino| mino.is_some()) {
                         self.state.board.remove(y);
-                        self.state.board.push(Default::default());
+                        self.state.board.push(self.config.empty_line());
                         self.state.lines_cleared += 1;
                     }
                 }
-                // Increment level if 10 lines cleared.
-                if self.mode.increment_level && self.state.lines_cleared % 10 == 0 {
-                    self.state.level = self.state.level.saturating_add(1);
+                // Let loose tile groups fall and check whether that completed any more lines.
+                let mut cascaded_lines = Vec::new();
+                if self.config.line_clear_gravity == LineClearGravity::Cascade
+                    && self.cascade_tiles()
+                {
+                    cascaded_lines = (0..self.config.height)
+                        .rev()
+                        .filter(|&y| self.state.board[y].iter().all(|mino| mino.is_some()))
+                        .collect();
+                }
+                if let Some(before) = board_before {
+                    if before != self.state.board {
+                        let after = self.state.board.clone();
+                        feedback_events
+                            .push((event_time, Feedback::BoardChanged { before, after }));
+                    }
+                }
+                // Increment level once for every multiple of 10 lines passed.
+                if self.mode.increment_level {
+                    let thresholds_passed =
+                        self.state.lines_cleared / 10 - lines_cleared_before / 10;
+                    for _ in 0..thresholds_passed {
+                        self.state.level = self.state.level.saturating_add(1);
+                        feedback_events.push((event_time, Feedback::LevelUp(self.state.level)));
+                    }
+                }
+                if cascaded_lines.is_empty() {
+                    self.state.events.insert(
+                        InternalEvent::Spawn,
+                        event_time + self.config.appearance_delay,
+                    );
+                } else {
+                    feedback_events.push((
+                        event_time,
+                        Feedback::LineClears(cascaded_lines, self.config.line_clear_delay),
+                    ));
+                    self.state.events.insert(
+                        InternalEvent::LineClear,
+                        event_time + self.config.line_clear_delay,
+                    );
                 }
-                self.state.events.insert(
-                    InternalEvent::Spawn,
-                    event_time + self.config.appearance_delay,
-                );
                 None
             }
         };
@@ -1387,31 +3877,38 @@ impl Game {
                 && (self.state.buttons_pressed[Button::MoveLeft]
                     != self.state.buttons_pressed[Button::MoveRight])
             {
-                self.state
-                    .events
-                    .insert(InternalEvent::MoveFast, event_time);
+                // A new piece only keeps auto-shifting if it was already charged for the last one.
+                let move_event = if event == InternalEvent::Spawn
+                    && !(self.config.das_carryover && self.state.das_charged)
+                {
+                    InternalEvent::MoveSlow
+                } else {
+                    InternalEvent::MoveFast
+                };
+                self.state.events.insert(move_event, event_time);
             }
             // No fall event scheduled but piece might be able to, schedule fall event.
             if !self.state.events.contains_key(&InternalEvent::Fall) {
-                let soft_drop = self.state.buttons_pressed[Button::DropSoft]
-                    .then_some(self.config.soft_drop_factor);
-                let drop_delay = Self::drop_delay(self.state.level, soft_drop);
+                let soft_drop = self.held_soft_drop_factor();
+                let drop_delay = self.drop_delay(soft_drop);
                 self.state
                     .events
                     .insert(InternalEvent::Fall, event_time + drop_delay);
             }
         }
         self.state.active_piece_data = next_piece.map(|next_piece| {
-            (
+            let mut locking_data = self.calculate_locking_data(
+                event,
+                event_time,
+                prev_piece_data,
                 next_piece,
-                self.calculate_locking_data(
-                    event,
-                    event_time,
-                    prev_piece_data,
-                    next_piece,
-                    next_piece.fits_at(&self.state.board, (0, -1)).is_none(),
-                ),
-            )
+                next_piece.fits_at(&self.state.board, (0, -1)).is_none(),
+            );
+            // Remember the last rotation's kick until the piece is repositioned otherwise.
+            if rotation_kick.is_some() || prev_piece != Some(next_piece) {
+                locking_data.last_kick = rotation_kick;
+            }
+            (next_piece, locking_data)
         });
         feedback_events
     }
@@ -1443,6 +3940,8 @@ impl Game {
                 last_liftoff: Some(event_time),
                 ground_time_left: self.config.ground_time_max,
                 lowest_y: next_piece.position.1,
+                last_kick: None,
+                lock_resets: 0,
             },
             // [2] Active piece lifted off the ground.
             (Some((_prev_piece, prev_locking_data)), false) if prev_locking_data.touches_ground => {
@@ -1455,7 +3954,7 @@ impl Game {
             }
             // [3] A piece is on the ground. Complex update to locking values.
             (prev_piece_data, true) => {
-                let next_locking_data = match prev_piece_data {
+                let mut next_locking_data = match prev_piece_data {
                     // If previous piece exists and next piece hasn't reached newest low (i.e. not a reset situation).
                     Some((_prev_piece, prev_locking_data))
                         if next_piece.position.1 >= prev_locking_data.lowest_y =>
@@ -1481,7 +3980,7 @@ impl Game {
                                 Some(last_touchdown) => {
                                     let (last_touchdown, ground_time_left) = if event_time
                                         .saturating_sub(last_liftoff)
-                                        <= 2 * Self::drop_delay(self.state.level, None)
+                                        <= 2 * self.drop_delay(None)
                                     {
                                         (
                                             prev_locking_data.last_touchdown,
@@ -1503,6 +4002,8 @@ impl Game {
                                         last_liftoff: None,
                                         ground_time_left,
                                         lowest_y: prev_locking_data.lowest_y,
+                                        last_kick: prev_locking_data.last_kick,
+                                        lock_resets: prev_locking_data.lock_resets,
                                     }
                                 }
                                 // Piece existed, was not touching ground, is touching ground now, but does not have a last touchdown. Just set touchdown.
@@ -1521,6 +4022,8 @@ impl Game {
                         last_liftoff: None,
                         ground_time_left: self.config.ground_time_max,
                         lowest_y: next_piece.position.1,
+                        last_kick: None,
+                        lock_resets: 0,
                     },
                 };
                 // Set lock timer if there isn't one, or refresh it if piece was moved.
@@ -1529,17 +4032,30 @@ impl Game {
                     .unwrap_or(false);
                 #[rustfmt::skip]
                 let move_rotate = matches!(event, InternalEvent::Rotate(_) | InternalEvent::MoveSlow | InternalEvent::MoveFast);
-                if !self.state.events.contains_key(&InternalEvent::LockTimer)
-                    || (repositioned && move_rotate)
-                {
+                let lock_timer_pending = self.state.events.contains_key(&InternalEvent::LockTimer);
+                // Once out of resets, moving no longer refreshes the lock timer.
+                let resets_exhausted = self
+                    .config
+                    .lock_delay_max_resets
+                    .is_some_and(|max_resets| next_locking_data.lock_resets >= max_resets);
+                let reset = lock_timer_pending && repositioned && move_rotate && !resets_exhausted;
+                if reset {
+                    next_locking_data.lock_resets += 1;
+                }
+                // No lock timer is needed if pieces lock immediately upon falling.
+                if !self.config.instant_lock && (!lock_timer_pending || reset) {
                     // SAFETY: We know this must be `Some` in this case.
                     let current_ground_time =
                         event_time.saturating_sub(next_locking_data.last_touchdown.unwrap());
                     let remaining_ground_time = next_locking_data
                         .ground_time_left
                         .saturating_sub(current_ground_time);
-                    let lock_timer =
-                        std::cmp::min(Self::lock_delay(&self.state.level), remaining_ground_time);
+                    // A piece out of resets that lands again (e.g. after being kicked up) locks immediately.
+                    let lock_timer = if resets_exhausted {
+                        Duration::ZERO
+                    } else {
+                        std::cmp::min(self.lock_delay(), remaining_ground_time)
+                    };
                     self.state
                         .events
                         .insert(InternalEvent::LockTimer, event_time + lock_timer);
@@ -1551,11 +4067,66 @@ impl Game {
         }
     }
 
-    /// The amount of time left for a piece to fall naturally, purely dependent on level
-    /// and an optional soft-drop-factor.
+    /// Rotates a piece by some right turns on the current board, returning it unchanged if it does
+    /// not fit, along with the kick used by the rotation system (if any).
+    fn rotate_piece(&self, piece: ActivePiece, turns: i32) -> (ActivePiece, Option<KickInfo>) {
+        if piece.shape == Tetromino::O
+            && self.config.rotation_system == RotationSystem::Ocular
+            && self.config.o_rotation == ORotation::Shift
+        {
+            let dx = match turns.rem_euclid(4) {
+                1 => 1,
+                3 => -1,
+                _ => 0,
+            };
+            let shifted_piece = piece.fits_at(&self.state.board, (dx, 0)).unwrap_or(piece);
+            return (shifted_piece, None);
+        }
+        match self
+            .config
+            .rotation_system
+            .rotate_with_kick(&piece, &self.state.board, turns)
+        {
+            Some((rotated_piece, kick)) => (rotated_piece, Some(kick)),
+            None => (piece, None),
+        }
+    }
+
+    /// The amount of time left for a piece to fall naturally, dependent on level, the configured
+    /// [`TwentyG`] behavior and an optional soft-drop-factor.
+    fn drop_delay(&self, soft_drop: Option<f64>) -> Duration {
+        let level = self.state.level;
+        let twenty_g = match self.config.twenty_g {
+            TwentyG::Auto(from_level) => level >= from_level,
+            TwentyG::Always => true,
+            TwentyG::Never => false,
+        };
+        let mut drop_delay = if twenty_g {
+            Duration::ZERO
+        } else {
+            self.config.gravity_curve.drop_delay(level)
+        };
+        if let Some(soft_drop_factor) = soft_drop {
+            drop_delay =
+                Duration::from_secs_f64(drop_delay.as_secs_f64() / soft_drop_factor.max(0.00001));
+        }
+        drop_delay
+    }
+
+    /// The standard gravity curve (c.f. [`GameConfig::gravity_curve`]), where pieces fall one row per
+    /// second at level 1 and speed up until level 19, after which the speed stays the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{num::NonZeroU32, time::Duration};
+    /// use tetrs_engine::Game;
+    ///
+    /// assert_eq!(Game::guideline_drop_delay(NonZeroU32::MIN), Duration::from_secs(1));
+    /// ```
     #[rustfmt::skip]
-    fn drop_delay(level: NonZeroU32, soft_drop: Option<f64>) -> Duration {
-        let mut drop_delay = Duration::from_nanos(match level.get() {
+    pub const fn guideline_drop_delay(level: NonZeroU32) -> Duration {
+        Duration::from_nanos(match level.get() {
              1 => 1_000_000_000,
              2 =>   793_000_000,
              3 =>   617_796_000,
@@ -1574,20 +4145,104 @@ impl Game {
             16 =>     4_263_557,
             17 =>     2_520_084,
             18 =>     1_457_139,
-            19 =>       823_907, // NOTE: 20G is at `833_333`, but falling speeds at that level are handled especially by the engine.
-             _ =>             0,
-        });
-        if let Some(soft_drop_factor) = soft_drop {
-            drop_delay = Duration::from_secs_f64(
-                drop_delay.as_secs_f64() / soft_drop_factor.max(0.00001),
-            );
+             _ =>       823_907, // NOTE: 20G is at `833_333`, but falling speeds past this level are handled by `TwentyG`.
+        })
+    }
+
+    /// A gravity curve in the style of 'TGM' (c.f. [`GameConfig::gravity_curve`]), which counts
+    /// every level as ten of TGM's internal levels and reaches 20G at level 51.
+    ///
+    /// TGM's gravity slowly builds up over its first 200 internal levels, abruptly drops back down
+    /// and then quickly accelerates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{num::NonZeroU32, time::Duration};
+    /// use tetrs_engine::Game;
+    ///
+    /// // 1/64 rows per frame at 60 frames per second.
+    /// assert_eq!(Game::tgm_drop_delay(NonZeroU32::MIN), Duration::from_nanos(1_066_666_666));
+    /// ```
+    #[rustfmt::skip]
+    pub const fn tgm_drop_delay(level: NonZeroU32) -> Duration {
+        let tgm_level = (level.get() - 1).saturating_mul(10);
+        // Gravity in 1/256 rows per frame.
+        let gravity: u64 = match tgm_level {
+              0..=29  =>    4,
+             30..=34  =>    6,
+             35..=39  =>    8,
+             40..=49  =>   10,
+             50..=59  =>   12,
+             60..=69  =>   16,
+             70..=79  =>   32,
+             80..=89  =>   48,
+             90..=99  =>   64,
+            100..=119 =>   80,
+            120..=139 =>   96,
+            140..=159 =>  112,
+            160..=169 =>  128,
+            170..=199 =>  144,
+            200..=219 =>    4,
+            220..=229 =>   32,
+            230..=232 =>   64,
+            233..=235 =>   96,
+            236..=238 =>  128,
+            239..=242 =>  160,
+            243..=246 =>  192,
+            247..=250 =>  224,
+            251..=299 =>  256,
+            300..=329 =>  512,
+            330..=359 =>  768,
+            360..=399 => 1024,
+            400..=419 => 1280,
+            420..=449 => 1024,
+            450..=499 =>  768,
+                    _ => 5120,
+        };
+        Duration::from_nanos(256 * 1_000_000_000 / 60 / gravity)
+    }
+
+    /// Awards points for a piece dropped by the given number of cells, if [`GameConfig::drop_scoring`]
+    /// is enabled.
+    fn add_drop_score(&mut self, cells: usize, points_per_cell: u32) {
+        if self.config.drop_scoring {
+            let points = u32::try_from(cells)
+                .unwrap_or(u32::MAX)
+                .saturating_mul(points_per_cell);
+            self.state.score = self.state.score.saturating_add(points);
         }
-        drop_delay
     }
 
-    /// The amount of time left for an common ground lock timer, purely dependent on level.
+    /// The factor by which falling is currently sped up due to 'soft drop' being held, if at all.
+    fn held_soft_drop_factor(&self) -> Option<f64> {
+        match self.config.soft_drop_mode {
+            SoftDropMode::Continuous => {
+                self.state.buttons_pressed[Button::DropSoft].then_some(self.config.soft_drop_factor)
+            }
+            SoftDropMode::StepPerPress => None,
+        }
+    }
+
+    /// The amount of time left for an common ground lock timer at the current level.
+    fn lock_delay(&self) -> Duration {
+        (self.config.lock_delay_curve)(self.state.level)
+    }
+
+    /// The standard lock delay curve (c.f. [`GameConfig::lock_delay_curve`]), which stays at 500ms
+    /// until level 19 and then shortens down to 150ms from level 30 onwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{num::NonZeroU32, time::Duration};
+    /// use tetrs_engine::Game;
+    ///
+    /// let level = NonZeroU32::new(28).unwrap();
+    /// assert_eq!(Game::guideline_lock_delay(level), Duration::from_millis(167));
+    /// ```
     #[rustfmt::skip]
-    const fn lock_delay(level: &NonZeroU32) -> Duration {
+    pub const fn guideline_lock_delay(level: NonZeroU32) -> Duration {
         Duration::from_millis(match level.get() {
             1..=19 => 500,
                 20 => 450,
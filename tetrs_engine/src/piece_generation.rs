@@ -4,15 +4,12 @@ This module handles random generation of [`Tetromino`]s.
 
 use std::num::NonZeroU32;
 
-use rand::{
-    self,
-    distributions::{Distribution, WeightedIndex},
-    prelude::SliceRandom,
-    rngs::ThreadRng,
-    Rng,
-};
+use rand::{self, Rng};
 
-use crate::Tetromino;
+use crate::{
+    rng::{shuffle, uniform_index, weighted_index},
+    Tetromino,
+};
 
 /// Handles the information of which pieces to spawn during a game.
 ///
@@ -56,6 +53,20 @@ pub enum TetrominoGenerator {
         /// at least one is `0`.
         relative_counts: [u32; 7],
     },
+    /// Classic 'NES' randomizer.
+    ///
+    /// It rolls among the seven [`Tetromino`] types plus a dummy 'none' outcome. If the roll is the
+    /// dummy or repeats the previous piece, it rerolls once among the seven types and keeps that
+    /// result. Repeats are thus rare (`1/28`) but possible, and droughts can be long.
+    Nes {
+        /// The previously generated piece, if any.
+        last_generated: Option<Tetromino>,
+    },
+    /// Generator that always yields the same [`Tetromino`], e.g. to practice with a specific piece.
+    Constant {
+        /// The piece type that is generated.
+        tetromino: Tetromino,
+    },
 }
 
 #[allow(dead_code)]
@@ -73,10 +84,30 @@ impl TetrominoGenerator {
         }
     }
 
+    /// Initialize a new instance of the [`TetrominoGenerator::Bag`] variant holding one of each piece
+    /// type ('7-bag').
+    pub fn seven_bag() -> Self {
+        Self::bag(NonZeroU32::MIN)
+    }
+
+    /// Initialize a new instance of the [`TetrominoGenerator::Bag`] variant holding `n` copies of
+    /// each piece type (e.g. '14-bag' for `n = 2`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or does not fit into a `u32`.
+    pub fn multi_bag(n: usize) -> Self {
+        let multiplicity = u32::try_from(n)
+            .ok()
+            .and_then(NonZeroU32::new)
+            .expect("bag multiplicity should be a positive u32");
+        Self::bag(multiplicity)
+    }
+
     /// Initialize a new instance of the [`TetrominoGenerator::Recency`] variant.
     pub fn recency() -> Self {
         let mut last_generated = [0, 1, 2, 3, 4, 5, 6];
-        last_generated.shuffle(&mut rand::thread_rng());
+        shuffle(&mut rand::thread_rng(), &mut last_generated);
         Self::Recency { last_generated }
     }
 
@@ -87,8 +118,82 @@ impl TetrominoGenerator {
         }
     }
 
+    /// Initialize a new instance of the [`TetrominoGenerator::Nes`] variant.
+    pub fn nes() -> Self {
+        Self::Nes {
+            last_generated: None,
+        }
+    }
+
+    /// Initialize a new instance of the [`TetrominoGenerator::Constant`] variant.
+    pub fn constant(tetromino: Tetromino) -> Self {
+        Self::Constant { tetromino }
+    }
+
+    /// Copies the generator including its internal state.
+    ///
+    /// This is unlike [`Clone::clone`], which produces a freshly initialized generator.
+    pub(crate) fn clone_with_state(&self) -> Self {
+        match self {
+            Self::Uniform => Self::Uniform,
+            Self::Bag {
+                pieces_left,
+                multiplicity,
+            } => Self::Bag {
+                pieces_left: *pieces_left,
+                multiplicity: *multiplicity,
+            },
+            Self::Recency { last_generated } => Self::Recency {
+                last_generated: *last_generated,
+            },
+            Self::TotalRelative { relative_counts } => Self::TotalRelative {
+                relative_counts: *relative_counts,
+            },
+            Self::Nes { last_generated } => Self::Nes {
+                last_generated: *last_generated,
+            },
+            Self::Constant { tetromino } => Self::Constant {
+                tetromino: *tetromino,
+            },
+        }
+    }
+
+    /// Returns a snapshot of the generator's internal state, e.g. to display upcoming probabilities.
+    pub fn peek_state(&self) -> GeneratorSnapshot {
+        let weights: [f64; 7] = match self {
+            Self::Uniform => [1.0; 7],
+            Self::Bag { pieces_left, .. } => pieces_left.map(|c| if c > 0 { 1.0 } else { 0.0 }),
+            Self::Recency { last_generated } => last_generated.map(recency_weight),
+            Self::TotalRelative { relative_counts } => relative_counts.map(total_relative_weight),
+            // A repeat needs the initial roll to be the dummy or repeat, and the reroll to repeat.
+            Self::Nes {
+                last_generated: Some(tetromino),
+            } => {
+                let mut weights = [1.0 / 8.0 + 2.0 / 8.0 / 7.0; 7];
+                weights[*tetromino] = 2.0 / 8.0 / 7.0;
+                weights
+            }
+            Self::Nes {
+                last_generated: None,
+            } => [1.0; 7],
+            Self::Constant { tetromino } => {
+                let mut weights = [0.0; 7];
+                weights[*tetromino] = 1.0;
+                weights
+            }
+        };
+        let total = weights.iter().sum::<f64>();
+        GeneratorSnapshot {
+            probabilities: weights.map(|w| w / total),
+            bag_remaining: match self {
+                Self::Bag { pieces_left, .. } => Some(*pieces_left),
+                _ => None,
+            },
+        }
+    }
+
     /// Method that allows `TetrominoGenerator` to be used as an [`Iterator`].
-    pub fn with_rng<'a, 'b>(&'a mut self, rng: &'b mut ThreadRng) -> TetrominoIterator<'a, 'b> {
+    pub fn with_rng<'a, 'b, R: Rng>(&'a mut self, rng: &'b mut R) -> TetrominoIterator<'a, 'b, R> {
         TetrominoIterator {
             tetromino_generator: self,
             rng,
@@ -100,34 +205,70 @@ impl Clone for TetrominoGenerator {
     fn clone(&self) -> Self {
         match self {
             Self::Uniform => Self::uniform(),
-            Self::Bag { multiplicity, .. } => Self::bag(*multiplicity),
+            Self::Bag { multiplicity, .. } => Self::Bag {
+                pieces_left: [multiplicity.get(); 7],
+                multiplicity: *multiplicity,
+            },
             Self::Recency { .. } => Self::recency(),
             Self::TotalRelative { .. } => Self::total_relative(),
+            Self::Nes { .. } => Self::nes(),
+            Self::Constant { tetromino } => Self::constant(*tetromino),
         }
     }
 }
 
+/// A snapshot of a [`TetrominoGenerator`]'s internal state, c.f. [`TetrominoGenerator::peek_state`].
+///
+/// All arrays are indexed by [`Tetromino`] (`impl Index<Tetromino> for [T; 7]`).
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneratorSnapshot {
+    /// The probability of each piece type being generated next, summing up to `1`.
+    pub probabilities: [f64; 7],
+    /// The number of each piece type left in the current bag, if the generator is a
+    /// [`TetrominoGenerator::Bag`].
+    pub bag_remaining: Option<[u32; 7]>,
+}
+
+/// Weight of a piece for [`TetrominoGenerator::Recency`], given how long ago it was last generated.
+fn recency_weight(last_generated: u32) -> f64 {
+    // NOTE: Computes `x.powf(2.5)` with correctly rounded operations only, so that the weights
+    // are the same on every platform.
+    let x = f64::from(last_generated);
+    x * x * x.sqrt()
+}
+
+/// Weight of a piece for [`TetrominoGenerator::TotalRelative`], given its relative count.
+fn total_relative_weight(relative_count: u32) -> f64 {
+    // Alternative weighing function: `1.0 / (f64::from(x) + 1.0);`
+    // NOTE: Computes `1.0 / x.exp()` with correctly rounded operations only, as above.
+    (0..relative_count).fold(1.0, |weight, _| weight / std::f64::consts::E)
+}
+
 /// Struct produced from [`TetrominoGenerator::with_rng`] which implements [`Iterator`].
-pub struct TetrominoIterator<'a, 'b> {
+pub struct TetrominoIterator<'a, 'b, R: Rng> {
     /// Selected tetromino generator to use as information source.
     pub tetromino_generator: &'a mut TetrominoGenerator,
-    /// Thread random number generator for raw soure of randomness.
-    pub rng: &'b mut ThreadRng,
+    /// Random number generator for raw soure of randomness.
+    pub rng: &'b mut R,
 }
 
-impl<'a, 'b> Iterator for TetrominoIterator<'a, 'b> {
+impl<'a, 'b, R: Rng> Iterator for TetrominoIterator<'a, 'b, R> {
     type Item = Tetromino;
 
     fn next(&mut self) -> Option<Self::Item> {
         match &mut self.tetromino_generator {
-            TetrominoGenerator::Uniform => Some(self.rng.gen_range(0..=6).try_into().unwrap()),
+            // SAFETY: 0 <= idx <= 6.
+            TetrominoGenerator::Uniform => Some(uniform_index(self.rng, 7).try_into().unwrap()),
             TetrominoGenerator::Bag {
                 pieces_left,
                 multiplicity,
             } => {
-                let weights = pieces_left.iter().map(|&c| if c > 0 { 1 } else { 0 });
-                // SAFETY: Struct invariant.
-                let idx = WeightedIndex::new(weights).unwrap().sample(&mut self.rng);
+                // Pick uniformly among the piece types still in the bag.
+                let available = pieces_left.iter().filter(|&&c| c > 0).count();
+                // SAFETY: Struct invariant, so `0 < available <= 7`.
+                let nth = uniform_index(self.rng, u32::try_from(available).unwrap());
+                let idx = (0..7).filter(|&i| pieces_left[i] > 0).nth(nth).unwrap();
                 // Update individual tetromino number and maybe replenish bag (ensuring invariant).
                 pieces_left[idx] -= 1;
                 if pieces_left.iter().sum::<u32>() == 0 {
@@ -137,10 +278,9 @@ impl<'a, 'b> Iterator for TetrominoIterator<'a, 'b> {
                 Some(idx.try_into().unwrap())
             }
             TetrominoGenerator::TotalRelative { relative_counts } => {
-                let weighing = |&x| 1.0 / f64::from(x).exp(); // Alternative weighing function: `1.0 / (f64::from(x) + 1.0);`
-                let weights = relative_counts.iter().map(weighing);
+                let weights = relative_counts.map(total_relative_weight);
                 // SAFETY: `weights` will always be non-zero due to `weighing`.
-                let idx = WeightedIndex::new(weights).unwrap().sample(&mut self.rng);
+                let idx = weighted_index(self.rng, &weights);
                 // Update individual tetromino counter and maybe rebalance all relative counts
                 relative_counts[idx] += 1;
                 // SAFETY: `self.relative_counts` always has a minimum.
@@ -154,10 +294,9 @@ impl<'a, 'b> Iterator for TetrominoIterator<'a, 'b> {
                 Some(idx.try_into().unwrap())
             }
             TetrominoGenerator::Recency { last_generated } => {
-                let weighing = |&x| f64::from(x).powf(2.5);
-                let weights = last_generated.iter().map(weighing);
+                let weights = last_generated.map(recency_weight);
                 // SAFETY: `weights` will always be non-zero due to `weighing`.
-                let idx = WeightedIndex::new(weights).unwrap().sample(&mut self.rng);
+                let idx = weighted_index(self.rng, &weights);
                 // Update all tetromino last_played values and maybe rebalance all relative counts..
                 for x in last_generated.iter_mut() {
                     *x += 1;
@@ -166,6 +305,17 @@ impl<'a, 'b> Iterator for TetrominoIterator<'a, 'b> {
                 // SAFETY: 0 <= idx <= 6.
                 Some(idx.try_into().unwrap())
             }
+            TetrominoGenerator::Nes { last_generated } => {
+                let mut idx = uniform_index(self.rng, 8);
+                if idx == 7 || last_generated.is_some_and(|t| t as usize == idx) {
+                    idx = uniform_index(self.rng, 7);
+                }
+                // SAFETY: 0 <= idx <= 6.
+                let tetromino = idx.try_into().unwrap();
+                *last_generated = Some(tetromino);
+                Some(tetromino)
+            }
+            TetrominoGenerator::Constant { tetromino } => Some(*tetromino),
         }
     }
 }
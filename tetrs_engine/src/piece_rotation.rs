@@ -2,7 +2,7 @@
 This module handles rotation of [`ActivePiece`]s.
 */
 
-use crate::{ActivePiece, Board, Orientation, Tetromino};
+use crate::{ActivePiece, Board, Offset, Orientation, Tetromino};
 
 /// Handles the logic of how to rotate a tetromino in play.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
@@ -16,24 +16,51 @@ pub enum RotationSystem {
     Super,
 }
 
+/// Details on how a rotation was resolved by a [`RotationSystem`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KickInfo {
+    /// The index of the offset used among all offsets tried by the rotation system.
+    ///
+    /// `0` means the preferred rotation fit, i.e. the piece did not need to be kicked.
+    pub index: usize,
+    /// The kick that was applied to the piece's position on top of [`KickInfo::centering`].
+    ///
+    /// This is `(0, 0)` when `index` is `0`.
+    pub offset: Offset,
+    /// The adjustment the rotation system makes to the piece's position when rotating it in place,
+    /// e.g. to keep it centered.
+    pub centering: Offset,
+}
+
+impl KickInfo {
+    /// The kick reported when a piece was not moved at all.
+    const NONE: Self = Self {
+        index: 0,
+        offset: (0, 0),
+        centering: (0, 0),
+    };
+}
+
 impl RotationSystem {
     /// Tries to rotate a piece with the chosen `RotationSystem`.
     ///
     /// This will return `None` if the rotation is not possible, and `Some(p)` if the rotation
     /// succeeded with `p` as the new state of the piece.
+    /// Rotating an O-piece always succeeds and returns it unchanged.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use tetrs_engine::*;
+    /// # use tetrs_engine::{*, piece_rotation::RotationSystem};
     /// # let game = Game::new(GameMode::marathon());
     /// # let empty_board = &game.state().board;
-    /// let i_piece = ActivePiece { shape: Tetromino::I, orientation: Orientation::N, pos: (0, 0) };
+    /// let i_piece = ActivePiece { shape: Tetromino::I, orientation: Orientation::N, position: (0, 0) };
     ///
     /// // Rotate left once.
     /// let i_rotated = RotationSystem::Ocular.rotate(&i_piece, empty_board, -1);
     ///
-    /// let i_expected = ActivePiece { shape: Tetromino::I, orientation: Orientation::W, pos: (1, 0) };
+    /// let i_expected = ActivePiece { shape: Tetromino::I, orientation: Orientation::W, position: (1, 0) };
     /// assert_eq!(i_rotated, Some(i_expected));
     /// ```
     pub fn rotate(
@@ -42,6 +69,39 @@ impl RotationSystem {
         board: &Board,
         right_turns: i32,
     ) -> Option<ActivePiece> {
+        self.rotate_with_kick(piece, board, right_turns)
+            .map(|(piece, _)| piece)
+    }
+
+    /// Tries to rotate a piece with the chosen `RotationSystem`, additionally reporting which kick
+    /// was used.
+    ///
+    /// This behaves like [`RotationSystem::rotate`], but returns the new state of the piece together
+    /// with [`KickInfo`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tetrs_engine::{*, piece_rotation::{KickInfo, RotationSystem}};
+    /// # let game = Game::new(GameMode::marathon());
+    /// # let empty_board = &game.state().board;
+    /// let t_piece = ActivePiece { shape: Tetromino::T, orientation: Orientation::N, position: (3, 5) };
+    ///
+    /// // Rotate right once in free air.
+    /// let (_, kick) = RotationSystem::Super.rotate_with_kick(&t_piece, empty_board, 1).unwrap();
+    ///
+    /// assert_eq!(kick, KickInfo { index: 0, offset: (0, 0), centering: (1, -1) });
+    /// ```
+    pub fn rotate_with_kick(
+        &self,
+        piece: &ActivePiece,
+        board: &Board,
+        right_turns: i32,
+    ) -> Option<(ActivePiece, KickInfo)> {
+        // The O-piece looks the same in every orientation, so rotating it changes nothing.
+        if piece.shape == Tetromino::O {
+            return Some((*piece, KickInfo::NONE));
+        }
         match self {
             RotationSystem::Classic => classic_rotate(piece, board, right_turns),
             RotationSystem::Super => super_rotate(piece, board, right_turns),
@@ -50,7 +110,11 @@ impl RotationSystem {
     }
 }
 
-fn ocular_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option<ActivePiece> {
+fn ocular_rotate(
+    piece: &ActivePiece,
+    board: &Board,
+    right_turns: i32,
+) -> Option<(ActivePiece, KickInfo)> {
     /*
     Symmetries : "OISZTLJ NESW ↺↻" and "-" mirror.
     O N      :
@@ -68,7 +132,7 @@ fn ocular_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option
     */
     let mut left = match right_turns.rem_euclid(4) {
         // No rotation occurred.
-        0 => return Some(*piece),
+        0 => return Some((*piece, KickInfo::NONE)),
         // One right rotation.
         1 => false,
         // 180 rotation will behave like two free-air rotations in a single press.
@@ -83,7 +147,7 @@ fn ocular_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option
                     W => [( 1, 0), ( 0, 0)].iter(),
                 },
             }.copied();
-            return piece.first_fit(board, kicks, 2);
+            return first_fit(piece, board, kicks, 2);
         }
         // One left rotation.
         3 => true,
@@ -162,73 +226,113 @@ fn ocular_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option
         }
     }.copied();
     if let Some(mx) = mirror {
-        piece.first_fit(board, kicks.map(|(x, y)| (mx - x, y)), right_turns)
+        first_fit(piece, board, kicks.map(|(x, y)| (mx - x, y)), right_turns)
     } else {
-        piece.first_fit(board, kicks, right_turns)
+        first_fit(piece, board, kicks, right_turns)
     }
 }
 
-fn super_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option<ActivePiece> {
-    let left = match right_turns.rem_euclid(4) {
+fn super_rotate(
+    piece: &ActivePiece,
+    board: &Board,
+    right_turns: i32,
+) -> Option<(ActivePiece, KickInfo)> {
+    let from = piece.orientation;
+    let to = from.rotate_r(right_turns);
+    if right_turns.rem_euclid(4) == 0 {
         // No rotation occurred.
-        0 => return Some(*piece),
-        // One right rotation.
-        1 => false,
-        // Some 180 rotation I came up with.
-        2 => {
-            #[rustfmt::skip]
-            let kicks = match piece.shape {
-                Tetromino::O | Tetromino::I | Tetromino::S | Tetromino::Z => [(0, 0)].iter(),
-                Tetromino::T | Tetromino::L | Tetromino::J => match piece.orientation {
-                    N => [( 0,-1), ( 0, 0)].iter(),
-                    E => [(-1, 0), ( 0, 0)].iter(),
-                    S => [( 0, 1), ( 0, 0)].iter(),
-                    W => [( 1, 0), ( 0, 0)].iter(),
-                },
-            }.copied();
-            return piece.first_fit(board, kicks, 2);
-        }
-        // One left rotation.
-        3 => true,
-        _ => unreachable!(),
+        return Some((*piece, KickInfo::NONE));
+    }
+    // Keep the piece rotating around the center of its SRS bounding box, then try each kick.
+    let (from_x, from_y) = srs_box_offset(piece.shape, from);
+    let (to_x, to_y) = srs_box_offset(piece.shape, to);
+    let base = (to_x - from_x, to_y - from_y);
+    let kicks = srs_kicks(piece.shape, from, to)
+        .iter()
+        .map(|(dx, dy)| (base.0 + dx, base.1 + dy));
+    first_fit(piece, board, kicks, right_turns)
+}
+
+/// Returns the offset of a piece's position from the bottom-left corner of its SRS bounding box.
+///
+/// J, L, S, T and Z pieces live in a 3x3 box, the I-piece in a 4x4 box, in which they rotate
+/// around the center.
+fn srs_box_offset(shape: Tetromino, orientation: Orientation) -> Offset {
+    use Orientation::*;
+    #[rustfmt::skip]
+    let offset = match shape {
+        Tetromino::O => (0, 0),
+        Tetromino::I => match orientation {
+            N => (0, 2), E => (2, 0), S => (0, 1), W => (1, 0),
+        },
+        Tetromino::S | Tetromino::Z | Tetromino::T | Tetromino::L | Tetromino::J => match orientation {
+            N => (0, 1), E => (1, 0), S => (0, 0), W => (0, 0),
+        },
     };
+    offset
+}
+
+/// Returns the SRS kicks to try, in order, when rotating a piece between two orientations.
+///
+/// Quarter turns use the standard guideline tables (separate ones for the I-piece and for all
+/// other pieces), half turns use the kicks popularized by 'SRS+'.
+fn srs_kicks(shape: Tetromino, from: Orientation, to: Orientation) -> &'static [Offset] {
     use Orientation::*;
     #[rustfmt::skip]
-    let kicks = match piece.shape {
-        Tetromino::O => [(0, 0)].iter(), // ⠶
-        Tetromino::I => match piece.orientation {
-            N => if left { [( 1,-2), ( 0,-2), ( 3,-2), ( 0, 0), ( 3,-3)].iter() }
-                    else { [( 2,-2), ( 0,-2), ( 3,-2), ( 0,-3), ( 3, 0)].iter() },
-            E => if left { [(-2, 2), ( 0, 2), (-3, 2), ( 0, 3), (-3, 0)].iter() }
-                    else { [( 2,-1), (-3, 1), ( 0, 1), (-3, 3), ( 0, 0)].iter() },
-            S => if left { [( 2,-1), ( 3,-1), ( 0,-1), ( 3,-3), ( 0, 0)].iter() }
-                    else { [( 1,-1), ( 3,-1), ( 0,-1), ( 3, 0), ( 0,-3)].iter() },
-            W => if left { [(-1, 1), (-3, 1), ( 0, 1), (-3, 0), ( 0, 3)].iter() }
-                    else { [(-1, 2), ( 0, 2), (-3, 2), ( 0, 0), (-3, 3)].iter() },
+    let kicks: &[Offset] = match shape {
+        Tetromino::O => &[( 0, 0)],
+        Tetromino::I => match (from, to) {
+            (N, E) => &[( 0, 0), (-2, 0), ( 1, 0), (-2,-1), ( 1, 2)],
+            (E, N) => &[( 0, 0), ( 2, 0), (-1, 0), ( 2, 1), (-1,-2)],
+            (E, S) => &[( 0, 0), (-1, 0), ( 2, 0), (-1, 2), ( 2,-1)],
+            (S, E) => &[( 0, 0), ( 1, 0), (-2, 0), ( 1,-2), (-2, 1)],
+            (S, W) => &[( 0, 0), ( 2, 0), (-1, 0), ( 2, 1), (-1,-2)],
+            (W, S) => &[( 0, 0), (-2, 0), ( 1, 0), (-2,-1), ( 1, 2)],
+            (W, N) => &[( 0, 0), ( 1, 0), (-2, 0), ( 1,-2), (-2, 1)],
+            (N, W) => &[( 0, 0), (-1, 0), ( 2, 0), (-1, 2), ( 2,-1)],
+            _ => half_turn_kicks(from),
         },
-        Tetromino::S | Tetromino::Z | Tetromino::T | Tetromino::L | Tetromino::J => match piece.orientation {
-            N => if left { [( 0,-1), ( 1,-1), ( 1, 0), ( 0,-3), ( 1,-3)].iter() }
-                    else { [( 1,-1), ( 0,-1), ( 0, 0), ( 1,-3), ( 0,-3)].iter() },
-            E => if left { [(-1, 1), ( 0, 1), ( 0, 0), (-1, 3), ( 0, 3)].iter() }
-                    else { [(-1, 0), ( 0, 0), ( 0,-1), (-1, 2), ( 0, 2)].iter() },
-            S => if left { [( 1, 0), ( 0, 0), (-1, 1), ( 1,-2), ( 0,-2)].iter() }
-                    else { [( 0, 0), ( 1, 0), ( 1, 1), ( 0,-2), ( 1,-2)].iter() },
-            W => if left { [( 0, 0), (-1, 0), (-1,-1), ( 0, 2), (-1, 2)].iter() }
-                    else { [( 0, 1), (-1, 1), (-1, 0), ( 0, 3), (-1, 3)].iter() },
+        Tetromino::S | Tetromino::Z | Tetromino::T | Tetromino::L | Tetromino::J => match (from, to) {
+            (N, E) => &[( 0, 0), (-1, 0), (-1, 1), ( 0,-2), (-1,-2)],
+            (E, N) => &[( 0, 0), ( 1, 0), ( 1,-1), ( 0, 2), ( 1, 2)],
+            (E, S) => &[( 0, 0), ( 1, 0), ( 1,-1), ( 0, 2), ( 1, 2)],
+            (S, E) => &[( 0, 0), (-1, 0), (-1, 1), ( 0,-2), (-1,-2)],
+            (S, W) => &[( 0, 0), ( 1, 0), ( 1, 1), ( 0,-2), ( 1,-2)],
+            (W, S) => &[( 0, 0), (-1, 0), (-1,-1), ( 0, 2), (-1, 2)],
+            (W, N) => &[( 0, 0), (-1, 0), (-1,-1), ( 0, 2), (-1, 2)],
+            (N, W) => &[( 0, 0), ( 1, 0), ( 1, 1), ( 0,-2), ( 1,-2)],
+            _ => half_turn_kicks(from),
         },
-    }.copied();
-    piece.first_fit(board, kicks, right_turns)
+    };
+    kicks
 }
 
-fn classic_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Option<ActivePiece> {
+/// Returns the SRS+ kicks for a half turn starting from the given orientation.
+fn half_turn_kicks(from: Orientation) -> &'static [Offset] {
+    use Orientation::*;
+    #[rustfmt::skip]
+    let kicks: &[Offset] = match from {
+        N => &[( 0, 0), ( 0, 1), ( 1, 1), (-1, 1), ( 1, 0), (-1, 0)],
+        E => &[( 0, 0), ( 1, 0), ( 1, 2), ( 1, 1), ( 0, 2), ( 0, 1)],
+        S => &[( 0, 0), ( 0,-1), (-1,-1), ( 1,-1), (-1, 0), ( 1, 0)],
+        W => &[( 0, 0), (-1, 0), (-1, 2), (-1, 1), ( 0, 2), ( 0, 1)],
+    };
+    kicks
+}
+
+fn classic_rotate(
+    piece: &ActivePiece,
+    board: &Board,
+    right_turns: i32,
+) -> Option<(ActivePiece, KickInfo)> {
     let left_rotation = match right_turns.rem_euclid(4) {
         // No rotation occurred.
-        0 => return Some(*piece),
+        0 => return Some((*piece, KickInfo::NONE)),
         // One right rotation.
         1 => false,
         // Classic didn't define 180 rotation, just check if the "default" 180 rotation fits.
         2 => {
-            return piece.fits_at_rotated(board, (0, 0), 2);
+            return first_fit(piece, board, [(0, 0)], 2);
         }
         // One left rotation.
         3 => true,
@@ -253,5 +357,33 @@ fn classic_rotate(piece: &ActivePiece, board: &Board, right_turns: i32) -> Optio
             W => if left_rotation { ( 0, 0) } else { ( 0, 1) }, // ⠲⠂ <- ⠺  -> ⠴⠄ // ⠖⠂ <- ⠹  -> ⠤⠆ // ⠒⠆ <- ⠼  -> ⠦⠄
         },
     };
-    piece.fits_at_rotated(board, kick, right_turns)
+    first_fit(piece, board, [kick], right_turns)
+}
+
+/// Like [`ActivePiece::first_fit`], but also reports which of the offsets was used.
+///
+/// The first offset is the preferred one, which the reported kicks are relative to.
+fn first_fit(
+    piece: &ActivePiece,
+    board: &Board,
+    offsets: impl IntoIterator<Item = Offset>,
+    right_turns: i32,
+) -> Option<(ActivePiece, KickInfo)> {
+    let mut offsets = offsets.into_iter().peekable();
+    let centering = *offsets.peek()?;
+    offsets.enumerate().find_map(|(index, (x, y))| {
+        piece
+            .fits_at_rotated(board, (x, y), right_turns)
+            .map(|new_piece| {
+                let offset = (x - centering.0, y - centering.1);
+                (
+                    new_piece,
+                    KickInfo {
+                        index,
+                        offset,
+                        centering,
+                    },
+                )
+            })
+    })
 }
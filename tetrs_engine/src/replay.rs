@@ -0,0 +1,130 @@
+/*!
+This module handles recording the inputs of a [`Game`] and replaying them deterministically.
+*/
+
+use crate::{
+    ButtonsPressed, FeedbackEvents, Game, GameConfig, GameMode, GameTime, GameUpdateError,
+};
+
+/// A recording of all inputs made in a seeded game, from which the game can be reproduced.
+///
+/// Game modifiers (c.f. [`Game::add_modifier`]) and forfeits are not part of a replay.
+///
+/// The function-valued options of [`Replay::config`] ([`GameConfig::scoring`] and
+/// [`GameConfig::lock_delay_curve`]) are not serialized, and a custom gravity curve
+/// ([`GravityCurve::Custom`](crate::GravityCurve::Custom)) cannot be.
+/// A deserialized replay of a game recorded with custom ones has to have them set again before it
+/// is played, or it will not reproduce the recorded game.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    /// The game mode the recorded game was played in.
+    pub mode: GameMode,
+    /// The configuration the recorded game was started with.
+    pub config: GameConfig,
+    /// The seed the recorded game was started with.
+    pub seed: u64,
+    /// Every successful call to [`Game::update`] in order, by its arguments.
+    pub inputs: Vec<(GameTime, Option<ButtonsPressed>)>,
+}
+
+impl Replay {
+    /// Reproduces the recorded game up to the given point in time.
+    ///
+    /// # Errors
+    ///
+    /// This function fails if a recorded input cannot be applied, i.e. the replay does not
+    /// reproduce the recorded game (e.g. because it was modified, or replayed with different
+    /// function-valued options than it was recorded with).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tetrs_engine::{replay::Recorder, Button, ButtonsPressed, GameConfig, GameMode};
+    ///
+    /// let mut recorder = Recorder::new(GameMode::zen(), GameConfig::default(), 42);
+    /// let mut hard_drop = ButtonsPressed::default();
+    /// hard_drop[Button::DropHard] = true;
+    /// for secs in 1..20 {
+    ///     let buttons_pressed = if secs % 2 == 0 { hard_drop } else { ButtonsPressed::default() };
+    ///     recorder.update(Some(buttons_pressed), Duration::from_secs(secs)).unwrap();
+    /// }
+    ///
+    /// let game = recorder.replay().play_to(Duration::from_secs(19)).unwrap();
+    /// assert_eq!(game.state(), recorder.game().state());
+    /// ```
+    pub fn play_to(&self, time: GameTime) -> Result<Game, GameUpdateError> {
+        let config = GameConfig {
+            tetromino_generator: self.config.tetromino_generator.clone_with_state(),
+            ..self.config.clone()
+        };
+        let mut game = Game::with_config_and_seed(self.mode.clone(), config, self.seed);
+        for &(update_time, new_button_state) in &self.inputs {
+            if time < update_time || game.ended() {
+                break;
+            }
+            game.update(new_button_state, update_time)?;
+        }
+        if !game.ended() && game.state().time < time {
+            game.update(None, time)?;
+        }
+        Ok(game)
+    }
+}
+
+/// Wrapper around a seeded [`Game`] which records all inputs made into a [`Replay`].
+#[derive(Debug)]
+pub struct Recorder {
+    game: Game,
+    replay: Replay,
+}
+
+impl Recorder {
+    /// Start a new seeded game to be recorded, c.f. [`Game::with_config_and_seed`].
+    pub fn new(game_mode: GameMode, config: GameConfig, seed: u64) -> Self {
+        let replay = Replay {
+            mode: game_mode.clone(),
+            config: GameConfig {
+                tetromino_generator: config.tetromino_generator.clone_with_state(),
+                ..config.clone()
+            },
+            seed,
+            inputs: Vec::new(),
+        };
+        Self {
+            game: Game::with_config_and_seed(game_mode, config, seed),
+            replay,
+        }
+    }
+
+    /// Updates the recorded game as by [`Game::update`], and records the call if it succeeded.
+    ///
+    /// # Errors
+    ///
+    /// This function fails in the same cases [`Game::update`] does.
+    pub fn update(
+        &mut self,
+        new_button_state: Option<ButtonsPressed>,
+        update_time: GameTime,
+    ) -> Result<FeedbackEvents, GameUpdateError> {
+        let feedback_events = self.game.update(new_button_state, update_time)?;
+        self.replay.inputs.push((update_time, new_button_state));
+        Ok(feedback_events)
+    }
+
+    /// Read accessor for the recorded game.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Read accessor for the replay recorded so far.
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Stops recording, returning the finished replay.
+    pub fn into_replay(self) -> Replay {
+        self.replay
+    }
+}
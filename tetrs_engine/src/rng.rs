@@ -0,0 +1,200 @@
+/*!
+This module handles the sources of randomness a game can use.
+
+Random numbers are turned into indices, weighted choices and shuffles by the engine itself (instead
+of `rand`'s distributions, whose output may change between versions), so that a seeded game deals
+the same pieces with every version of `rand`.
+*/
+
+use rand::{rngs::ThreadRng, Error, RngCore};
+
+/// Selects which source of randomness a game uses.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RngSource {
+    /// The thread-local random number generator of the `rand` crate, seeded by the system.
+    Thread,
+    /// The engine's own [`Pcg32`] generator, initialized with the given seed.
+    ///
+    /// This produces the same sequence of random numbers on every platform, independent of the
+    /// version of the `rand` crate.
+    Pcg32 {
+        /// The seed used to initialize the generator.
+        seed: u64,
+    },
+}
+
+/// The random number generator used by a game, as selected by a [`RngSource`].
+#[derive(Clone, Debug)]
+pub enum GameRng {
+    /// Variant for [`RngSource::Thread`].
+    Thread(ThreadRng),
+    /// Variant for [`RngSource::Pcg32`].
+    Pcg32(Pcg32),
+}
+
+impl GameRng {
+    /// Initialize a new random number generator from the given source.
+    pub fn from_source(rng_source: &RngSource) -> Self {
+        match rng_source {
+            RngSource::Thread => Self::Thread(rand::thread_rng()),
+            RngSource::Pcg32 { seed } => Self::Pcg32(Pcg32::new(*seed, Pcg32::DEFAULT_STREAM)),
+        }
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::Thread(rng) => rng.next_u32(),
+            Self::Pcg32(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::Thread(rng) => rng.next_u64(),
+            Self::Pcg32(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Self::Thread(rng) => rng.fill_bytes(dest),
+            Self::Pcg32(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self {
+            Self::Thread(rng) => rng.try_fill_bytes(dest),
+            Self::Pcg32(rng) => rng.try_fill_bytes(dest),
+        }
+    }
+}
+
+/// A small, version-pinned implementation of the 'PCG-XSH-RR' random number generator with 64-bit
+/// state and 32-bit output (see <https://www.pcg-random.org>).
+///
+/// # Examples
+///
+/// The sequence generated for a given seed and stream will never change:
+///
+/// ```
+/// use rand::RngCore;
+/// use tetrs_engine::rng::Pcg32;
+///
+/// let mut rng = Pcg32::new(42, 54);
+/// let outputs = [(); 6].map(|_| rng.next_u32());
+/// assert_eq!(
+///     outputs,
+///     [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+/// );
+/// ```
+#[derive(Eq, PartialEq, Clone, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    /// The stream used by [`RngSource::Pcg32`].
+    pub const DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+    const MULTIPLIER: u64 = 6364136223846793005;
+
+    /// Initialize a new generator given a seed and a stream selector.
+    ///
+    /// Different streams produce different sequences for the same seed.
+    pub fn new(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+}
+
+impl RngCore for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+        // NOTE: Truncation intended.
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = u64::from(self.next_u32());
+        let high = u64::from(self.next_u32());
+        (high << 32) | low
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Returns a uniformly random index below `n`.
+///
+/// Raw outputs from the last incomplete run of `n` values are rejected so that no index is favored.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub(crate) fn uniform_index<R: RngCore + ?Sized>(rng: &mut R, n: u32) -> usize {
+    assert!(n > 0, "cannot pick an index below zero");
+    let zone = u32::MAX - u32::MAX % n;
+    loop {
+        let x = rng.next_u32();
+        if x < zone {
+            // SAFETY: `x % n < n <= u32::MAX`.
+            return usize::try_from(x % n).unwrap();
+        }
+    }
+}
+
+/// Returns a random index, picked with probability proportional to its weight.
+///
+/// # Panics
+///
+/// Panics if no weight is positive.
+pub(crate) fn weighted_index<R: RngCore + ?Sized>(rng: &mut R, weights: &[f64]) -> usize {
+    let total = weights.iter().sum::<f64>();
+    assert!(total > 0.0, "cannot pick from weights {weights:?}");
+    // NOTE: 53 random bits make for a uniformly distributed `f64` in `[0, 1)`, exactly.
+    let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    let mut x = unit * total;
+    for (idx, &weight) in weights.iter().enumerate() {
+        if x < weight {
+            return idx;
+        }
+        x -= weight;
+    }
+    // Rounding errors may leave `x` just past the end, which belongs to the last possible index.
+    // SAFETY: Some weight is positive.
+    weights.iter().rposition(|&weight| weight > 0.0).unwrap()
+}
+
+/// Shuffles the given elements uniformly at random ('Fisher-Yates').
+pub(crate) fn shuffle<R: RngCore + ?Sized, T>(rng: &mut R, elements: &mut [T]) {
+    for i in (1..elements.len()).rev() {
+        // SAFETY: Slices handled here are short.
+        let j = uniform_index(rng, u32::try_from(i + 1).unwrap());
+        elements.swap(i, j);
+    }
+}
@@ -0,0 +1,89 @@
+//! Checks the board statistics of [`analysis`] on hand-built boards.
+
+use std::num::NonZeroU32;
+
+use tetrs_engine::{analysis, Board, GameConfig};
+
+/// Builds a default-sized board whose bottom rows are given from top to bottom, with `#` marking
+/// filled cells.
+fn board(rows: &[&str]) -> Board {
+    let mut board = GameConfig::default().empty_board();
+    for (y, row) in rows.iter().rev().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            if c == '#' {
+                board[y][x] = Some(NonZeroU32::MIN);
+            }
+        }
+    }
+    board
+}
+
+#[test]
+fn empty_board() {
+    let board = board(&[]);
+    assert_eq!(analysis::column_heights(&board), [0; 10]);
+    assert_eq!(analysis::count_holes(&board), 0);
+    assert_eq!(analysis::aggregate_bumpiness(&board), 0);
+    assert_eq!(analysis::surface_profile(&board), [0; 9]);
+}
+
+#[test]
+fn overhangs() {
+    #[rustfmt::skip]
+    let board = board(&[
+        ".###......",
+        "..#...#...",
+        "......##..",
+    ]);
+    assert_eq!(
+        analysis::column_heights(&board),
+        [0, 3, 3, 3, 0, 0, 2, 1, 0, 0]
+    );
+    // Two cells below columns 1 and 3 each, one below column 2, none below the piece on the right.
+    assert_eq!(analysis::count_holes(&board), 5);
+    assert_eq!(analysis::aggregate_bumpiness(&board), 3 + 3 + 2 + 1 + 1);
+    assert_eq!(
+        analysis::surface_profile(&board),
+        [3, 0, 0, -3, 0, 2, -1, -1, 0]
+    );
+}
+
+#[test]
+fn empty_columns() {
+    #[rustfmt::skip]
+    let board = board(&[
+        "#########.",
+        "#########.",
+        "#.#######.",
+    ]);
+    assert_eq!(
+        analysis::column_heights(&board),
+        [3, 3, 3, 3, 3, 3, 3, 3, 3, 0]
+    );
+    assert_eq!(analysis::count_holes(&board), 1);
+    assert_eq!(analysis::aggregate_bumpiness(&board), 3);
+    assert_eq!(
+        analysis::surface_profile(&board),
+        [0, 0, 0, 0, 0, 0, 0, 0, -3]
+    );
+}
+
+#[test]
+fn filled_full_column() {
+    let mut board = board(&[]);
+    let height = board.len();
+    for line in &mut board {
+        line[4] = Some(NonZeroU32::MIN);
+    }
+    assert_eq!(
+        analysis::column_heights(&board),
+        [0, 0, 0, 0, height, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(analysis::count_holes(&board), 0);
+    assert_eq!(analysis::aggregate_bumpiness(&board), 2 * height);
+    let h = isize::try_from(height).unwrap();
+    assert_eq!(
+        analysis::surface_profile(&board),
+        [0, 0, 0, h, -h, 0, 0, 0, 0]
+    );
+}
@@ -0,0 +1,71 @@
+//! Checks that [`Feedback::BoardChanged`] is emitted exactly when the board changes, if enabled.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    Board, Button, ButtonsPressed, Feedback, FeedbackEvents, Game, GameConfig, GameMode,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// The boards before and after each emitted [`Feedback::BoardChanged`].
+type BoardChanges = Vec<(Board, Board)>;
+
+/// Returns the board changes among the given feedback.
+fn board_changes(feedback_events: FeedbackEvents) -> BoardChanges {
+    feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::BoardChanged { before, after } => Some((before, after)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Moves the first piece and then hard drops it, returning the board changes caused by each.
+fn move_and_lock(emit_board_changes: bool) -> (BoardChanges, BoardChanges) {
+    let config = GameConfig {
+        emit_board_changes,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let mut move_left = ButtonsPressed::default();
+    move_left[Button::MoveLeft] = true;
+    time += STEP;
+    let moved = game.update(Some(move_left), time).unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut locked = game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played.iter().sum::<u32>() == 0 {
+        time += STEP;
+        locked.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    (board_changes(moved), board_changes(locked))
+}
+
+#[test]
+fn lock_changes_board_but_move_does_not() {
+    let (moved, locked) = move_and_lock(true);
+    assert!(moved.is_empty());
+    let [(before, after)] = &locked[..] else {
+        panic!("expected one board change, got {}", locked.len());
+    };
+    assert!(before.iter().flatten().all(Option::is_none));
+    assert_eq!(
+        after.iter().flatten().filter(|cell| cell.is_some()).count(),
+        4
+    );
+}
+
+#[test]
+fn board_changes_are_off_by_default() {
+    let (moved, locked) = move_and_lock(false);
+    assert!(moved.is_empty());
+    assert!(locked.is_empty());
+}
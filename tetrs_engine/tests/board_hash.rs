@@ -0,0 +1,70 @@
+//! Checks that the board fingerprint agrees between games fed the same inputs and tells apart those
+//! that were not.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Game, GameConfig, GameMode,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Hard drops five pieces, first moving each in the direction given for it (if any), and returns
+/// the board fingerprint afterwards.
+fn board_hash_after(moves: [Option<Button>; 5]) -> u64 {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::seven_bag(),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    for (placed, button) in (1..).zip(moves) {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        if let Some(button) = button {
+            let mut buttons_pressed = ButtonsPressed::default();
+            buttons_pressed[button] = true;
+            time += STEP;
+            game.update(Some(buttons_pressed), time).unwrap();
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        let mut drop = ButtonsPressed::default();
+        drop[Button::DropHard] = true;
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        while game.state().pieces_played.iter().sum::<u32>() < placed {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+    }
+    game.state().board_hash()
+}
+
+#[test]
+fn same_inputs_give_same_hash() {
+    let moves = [
+        None,
+        Some(Button::MoveLeft),
+        Some(Button::MoveRight),
+        None,
+        None,
+    ];
+    assert_eq!(board_hash_after(moves), board_hash_after(moves));
+}
+
+#[test]
+fn different_input_gives_different_hash() {
+    let moves = [
+        None,
+        Some(Button::MoveLeft),
+        Some(Button::MoveRight),
+        None,
+        None,
+    ];
+    let mut diverged = moves;
+    diverged[3] = Some(Button::MoveLeft);
+    assert_ne!(board_hash_after(moves), board_hash_after(diverged));
+}
@@ -0,0 +1,107 @@
+//! Checks that cascade gravity lets loose tiles fall after a line clear, possibly clearing more lines
+//! (reported in cascade order, even at the same time).
+
+mod common;
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Coord, Feedback,
+    Game, GameConfig, GameMode, GameTime, LineClearGravity, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Bottom row with a covered hole in the right column, a second row missing only its left tile, and
+/// a single loose tile on top which ends up right above the hole once the second row is cleared.
+fn cascade_board() -> Vec<Coord> {
+    let mut cells = vec![(9, 2)];
+    for x in 0..Game::WIDTH {
+        if x != 9 {
+            cells.push((x, 0));
+        }
+        if x != 0 {
+            cells.push((x, 1));
+        }
+    }
+    cells
+}
+
+/// Hard drops a vertical I-piece into the left column of the prepared board and plays until the next
+/// piece spawns, returning all line clears emitted (with their time) and the total lines cleared.
+fn play_with(config: GameConfig) -> (Vec<(GameTime, Vec<usize>)>, usize) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..config
+    };
+    let cells = cascade_board();
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        common::fill_cells(board, &cells);
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::E,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::I] == 0 || game.state().active_piece_data.is_none()
+    {
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    let line_clears = feedback_events
+        .into_iter()
+        .filter_map(|(feedback_time, feedback)| match feedback {
+            Feedback::LineClears(lines, _) => Some((feedback_time, lines)),
+            _ => None,
+        })
+        .collect();
+    (line_clears, game.state().lines_cleared)
+}
+
+/// Plays the prepared board as by [`play_with`], returning only the lines of each line clear.
+fn play(line_clear_gravity: LineClearGravity) -> (Vec<Vec<usize>>, usize) {
+    let (line_clears, lines_cleared) = play_with(GameConfig {
+        line_clear_gravity,
+        ..GameConfig::default()
+    });
+    let line_clears = line_clears.into_iter().map(|(_, lines)| lines).collect();
+    (line_clears, lines_cleared)
+}
+
+#[test]
+fn naive_gravity_leaves_tiles_floating() {
+    let (line_clears, lines_cleared) = play(LineClearGravity::Naive);
+    assert_eq!(line_clears, vec![vec![1]]);
+    assert_eq!(lines_cleared, 1);
+}
+
+#[test]
+fn cascade_gravity_clears_again() {
+    let (line_clears, lines_cleared) = play(LineClearGravity::Cascade);
+    assert_eq!(line_clears, vec![vec![1], vec![0]]);
+    assert_eq!(lines_cleared, 2);
+}
+
+#[test]
+fn simultaneous_cascade_clears_keep_their_order() {
+    let (line_clears, _) = play_with(GameConfig {
+        line_clear_gravity: LineClearGravity::Cascade,
+        line_clear_delay: Duration::ZERO,
+        ..GameConfig::default()
+    });
+    let [(first_time, first_lines), (second_time, second_lines)] = &line_clears[..] else {
+        panic!("expected two line clears, got {line_clears:?}");
+    };
+    assert_eq!(first_time, second_time);
+    assert_eq!((first_lines, second_lines), (&vec![1], &vec![0]));
+}
@@ -0,0 +1,28 @@
+//! Checks that [`GameConfig::max_catchup`] limits how far a single update advances the game.
+
+use std::time::Duration;
+
+use tetrs_engine::{Game, GameConfig, GameMode};
+
+#[test]
+fn large_jump_advances_only_max_catchup() {
+    let config = GameConfig {
+        max_catchup: Some(Duration::from_secs(1)),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let ten_minutes = Duration::from_secs(10 * 60);
+    game.update(None, ten_minutes).unwrap();
+    assert_eq!(game.state().time, Duration::from_secs(1));
+    // The remainder is caught up on by later calls.
+    game.update(None, ten_minutes).unwrap();
+    assert_eq!(game.state().time, Duration::from_secs(2));
+}
+
+#[test]
+fn large_jump_advances_fully_by_default() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    let one_minute = Duration::from_secs(60);
+    game.update(None, one_minute).unwrap();
+    assert_eq!(game.state().time, one_minute);
+}
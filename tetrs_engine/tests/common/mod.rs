@@ -0,0 +1,35 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::num::NonZeroU32;
+
+use tetrs_engine::{Board, Coord, Game, GameConfig, GameMode, GameState};
+
+/// Starts a seeded game whose initial state was prepared by `prepare`, e.g. to begin with some
+/// lines already cleared.
+pub fn game_with_state(
+    game_mode: GameMode,
+    config: GameConfig,
+    prepare: impl FnOnce(&mut GameState),
+) -> Game {
+    let game = Game::with_config_and_seed(game_mode.clone(), config, 0);
+    let mut state = game.state().clone();
+    prepare(&mut state);
+    Game::with_initial_state(game_mode, game.config().clone(), state).unwrap()
+}
+
+/// Starts a seeded game whose board was prepared by `prepare`, e.g. to build a specific stack.
+pub fn game_with_board(
+    game_mode: GameMode,
+    config: GameConfig,
+    prepare: impl FnOnce(&mut Board),
+) -> Game {
+    game_with_state(game_mode, config, |state| prepare(&mut state.board))
+}
+
+/// Fills in the given board cells.
+pub fn fill_cells(board: &mut Board, cells: &[Coord]) {
+    for &(x, y) in cells {
+        board[y][x] = Some(NonZeroU32::MIN);
+    }
+}
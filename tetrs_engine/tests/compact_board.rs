@@ -0,0 +1,47 @@
+#![cfg(feature = "serde")]
+
+use std::num::NonZeroU32;
+
+use tetrs_engine::{Board, Game, Tetromino};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Puzzle {
+    #[serde(with = "tetrs_engine::compact_board")]
+    board: Board,
+}
+
+fn sample_board() -> Board {
+    let mut board = vec![vec![None; Game::WIDTH].into_boxed_slice(); Game::HEIGHT];
+    board[0][..4].fill(Some(Tetromino::I.tiletypeid()));
+    board[0][5] = NonZeroU32::new(254);
+    board[1][0] = Some(Tetromino::T.tiletypeid());
+    board[2][9] = Some(Tetromino::Z.tiletypeid());
+    board
+}
+
+#[test]
+fn board_round_trips_through_compact_format() {
+    let puzzle = Puzzle {
+        board: sample_board(),
+    };
+    let json = serde_json::to_string(&puzzle).unwrap();
+    assert_eq!(
+        json,
+        r#"{"board":[".........Z","T.........","IIII.{254}...."]}"#
+    );
+    let restored: Puzzle = serde_json::from_str(&json).unwrap();
+    // Empty rows above the stack are not stored.
+    assert_eq!(restored.board, puzzle.board[..3]);
+}
+
+#[test]
+fn empty_board_has_no_rows() {
+    let restored: Puzzle = serde_json::from_str(r#"{"board":[]}"#).unwrap();
+    assert!(restored.board.is_empty());
+}
+
+#[test]
+fn compact_format_rejects_ragged_rows() {
+    let result = serde_json::from_str::<Puzzle>(r#"{"board":["..","..."]}"#);
+    assert!(result.is_err());
+}
@@ -0,0 +1,26 @@
+//! Checks that the countdown before the first spawn ticks every second and holds back the first piece.
+
+use std::time::Duration;
+
+use tetrs_engine::{Feedback, Game, GameConfig, GameMode};
+
+#[test]
+fn countdown_ticks_before_first_spawn() {
+    let config = GameConfig {
+        start_countdown: Duration::from_secs(3),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let feedback_events = game.update(None, Duration::from_millis(2900)).unwrap();
+    assert!(game.state().active_piece_data.is_none());
+    let ticks = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::Countdown(remaining) => Some(remaining.as_secs()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(ticks, [3, 2, 1]);
+    game.update(None, Duration::from_secs(3)).unwrap();
+    assert!(game.state().active_piece_data.is_some());
+}
@@ -0,0 +1,67 @@
+//! Checks that a charged auto-shift ('DAS') carries over to newly spawned pieces if configured.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Game, GameConfig, GameMode,
+    Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Holds 'move left' throughout, hard drops the first piece once it reached the wall, and returns
+/// the column of the second piece shortly after it spawned.
+fn column_after_spawn(das_carryover: bool) -> usize {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::T),
+        delayed_auto_shift: Duration::from_millis(167),
+        auto_repeat_rate: Duration::from_millis(33),
+        das_carryover,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut move_left = ButtonsPressed::default();
+    move_left[Button::MoveLeft] = true;
+    let mut move_left_hard_drop = move_left;
+    move_left_hard_drop[Button::DropHard] = true;
+
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    // Charge and shift the first piece all the way to the left.
+    time += STEP;
+    game.update(Some(move_left), time).unwrap();
+    time += Duration::from_millis(500);
+    game.update(None, time).unwrap();
+    assert!(game.state().das_charged);
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    assert_eq!(piece.position.0, 0);
+    // Drop it while keeping 'move left' held.
+    time += STEP;
+    game.update(Some(move_left_hard_drop), time).unwrap();
+    time += STEP;
+    game.update(Some(move_left), time).unwrap();
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    // Check well before a fresh charge would be complete.
+    time += Duration::from_millis(100);
+    game.update(None, time).unwrap();
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    assert_eq!(game.state().pieces_played[Tetromino::T], 1);
+    piece.position.0
+}
+
+#[test]
+fn charge_carries_over() {
+    assert_eq!(column_after_spawn(true), 0);
+}
+
+#[test]
+fn charge_resets_without_carryover() {
+    // The piece spawned at column 3 and moved only once.
+    assert_eq!(column_after_spawn(false), 2);
+}
@@ -0,0 +1,62 @@
+//! Checks that games can be played on boards of non-default dimensions.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(50);
+
+#[test]
+fn six_wide_board() {
+    let config = GameConfig {
+        width: 6,
+        height: 16,
+        skyline: 12,
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::O),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    assert_eq!(game.state().board.len(), 16);
+    assert!(game.state().board.iter().all(|line| line.len() == 6));
+
+    let mut time = Duration::ZERO;
+    let mut hard_drop = ButtonsPressed::default();
+    hard_drop[Button::DropHard] = true;
+    // Three O-pieces side by side fill the bottom two rows.
+    for x in [0, 2, 4] {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(None, time).unwrap();
+        }
+        let (active_piece, _) = game.state().active_piece_data.unwrap();
+        // Pieces spawn horizontally centered at the skyline, and immediately fall by one.
+        assert_eq!(active_piece.position, (2, 11));
+        game.set_active_piece(ActivePiece {
+            shape: Tetromino::O,
+            orientation: Orientation::N,
+            position: (x, 12),
+        })
+        .unwrap();
+        time += STEP;
+        game.update(Some(hard_drop), time).unwrap();
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+        assert_eq!(game.validate_invariants(), Ok(()));
+    }
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+
+    assert_eq!(game.state().lines_cleared, 2);
+    assert!(game
+        .state()
+        .board
+        .iter()
+        .flatten()
+        .all(|cell| cell.is_none()));
+    assert_eq!(game.validate_invariants(), Ok(()));
+}
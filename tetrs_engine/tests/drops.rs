@@ -0,0 +1,138 @@
+//! Checks that dropping pieces earns points per cell traveled if enabled, which drops lock, how far
+//! a piece would drop, and how far a held soft drop moves a piece.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Feedback, Game, GameConfig,
+    GameMode, SoftDropMode, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Spawns an I-piece onto an empty board and presses `button` once, returning the height the piece
+/// started at and the score afterwards.
+fn drop_once(button: Button, drop_scoring: bool) -> (usize, u32) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        soft_drop_mode: SoftDropMode::StepPerPress,
+        drop_scoring,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    let mut buttons_pressed = ButtonsPressed::default();
+    buttons_pressed[button] = true;
+    time += STEP;
+    game.update(Some(buttons_pressed), time).unwrap();
+    (piece.position.1, game.state().score)
+}
+
+#[test]
+fn hard_drop_scores_two_per_cell() {
+    let (spawn_y, score) = drop_once(Button::DropHard, true);
+    assert!(spawn_y > 0);
+    assert_eq!(score, 2 * u32::try_from(spawn_y).unwrap());
+}
+
+#[test]
+fn soft_drop_scores_one_per_cell() {
+    let (_, score) = drop_once(Button::DropSoft, true);
+    assert_eq!(score, 1);
+}
+
+#[test]
+fn drops_score_nothing_by_default() {
+    assert_eq!(drop_once(Button::DropHard, false).1, 0);
+    assert_eq!(drop_once(Button::DropSoft, false).1, 0);
+}
+
+#[test]
+fn sonic_drop_emits_quick_drop_without_locking() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    let mut sonic_drop = ButtonsPressed::default();
+    sonic_drop[Button::DropSonic] = true;
+    time += STEP;
+    let feedback_events = game.update(Some(sonic_drop), time).unwrap();
+    let dropped_piece = piece.well_piece(&game.state().board);
+    let quick_drops = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::QuickDrop(from, to) => Some((from, to)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(quick_drops, [(piece, dropped_piece)]);
+    // Unlike a hard drop, the piece rests on the ground for the usual lock delay.
+    time += Duration::from_millis(100);
+    game.update(Some(ButtonsPressed::default()), time).unwrap();
+    assert_eq!(game.state().pieces_played[piece.shape], 0);
+    assert_eq!(
+        game.state().active_piece_data.map(|(piece, _)| piece),
+        Some(dropped_piece)
+    );
+}
+
+#[test]
+fn spawned_piece_reports_distance_to_floor() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    assert_eq!(game.drop_distance(), None);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    assert!(piece.position.1 > 0);
+    assert_eq!(game.drop_distance(), Some(piece.position.1));
+}
+
+/// Spawns an I-piece onto an empty board and holds 'soft drop' for half a second, returning how many
+/// rows the piece moved down.
+fn hold_soft_drop(soft_drop_mode: SoftDropMode) -> usize {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        soft_drop_mode,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    let mut soft_drop = ButtonsPressed::default();
+    soft_drop[Button::DropSoft] = true;
+    for _ in 0..50 {
+        time += STEP;
+        game.update(Some(soft_drop), time).unwrap();
+    }
+    let (held_piece, _) = game.state().active_piece_data.unwrap();
+    piece.position.1 - held_piece.position.1
+}
+
+#[test]
+fn held_soft_drop_steps_once_per_press() {
+    assert_eq!(hold_soft_drop(SoftDropMode::StepPerPress), 1);
+}
+
+#[test]
+fn held_soft_drop_keeps_falling_continuously() {
+    assert!(hold_soft_drop(SoftDropMode::Continuous) > 1);
+}
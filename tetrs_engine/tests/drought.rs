@@ -0,0 +1,50 @@
+//! Checks that the drought counters track how many pieces spawned since each tetromino was seen.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    Button, ButtonsPressed, Game, GameMode, InternalEvent, ModifierPoint, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn droughts_grow_until_seen() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    let mut spawns = 0;
+    // SAFETY: Only decides the upcoming piece, which the generator could have chosen as well.
+    unsafe {
+        game.add_modifier(Box::new(move |_, _, state, _, point| {
+            if *point == ModifierPoint::BeforeEvent(InternalEvent::Spawn) {
+                let shape = [Tetromino::O, Tetromino::T][spawns % 2];
+                state.next_pieces.push_front(shape);
+                spawns += 1;
+            }
+        }));
+    }
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    let mut time = Duration::ZERO;
+    let mut previous = None;
+    for spawned in 1..=6 {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        let shape = game.state().active_piece_data.unwrap().0.shape;
+        let drought = game.state().drought();
+        assert_eq!(drought[shape], 0);
+        assert_eq!(drought[Tetromino::I], u32::try_from(spawned).unwrap());
+        if let Some(previous) = previous {
+            assert_ne!(shape, previous);
+            assert_eq!(drought[previous], 1);
+        }
+        previous = Some(shape);
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        while game.state().pieces_played.iter().sum::<u32>() < u32::try_from(spawned).unwrap() {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+    }
+}
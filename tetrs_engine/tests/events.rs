@@ -0,0 +1,85 @@
+//! Checks that events scheduled for the same time are handled in the declaration order of
+//! [`InternalEvent`], as they were when events were kept in a plain map.
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use tetrs_engine::{
+    Button, ButtonsPressed, EventMap, Game, GameMode, InternalEvent, ModifierPoint,
+};
+
+/// All kinds of events, in declaration order.
+const EVENTS: [InternalEvent; 12] = [
+    InternalEvent::LineClear,
+    InternalEvent::Spawn,
+    InternalEvent::Lock,
+    InternalEvent::HardDrop,
+    InternalEvent::SonicDrop,
+    InternalEvent::SoftDrop,
+    InternalEvent::Fall,
+    InternalEvent::MoveSlow,
+    InternalEvent::MoveFast,
+    InternalEvent::Rotate(1),
+    InternalEvent::LockTimer,
+    InternalEvent::Countdown,
+];
+
+#[test]
+fn ties_are_broken_by_declaration_order() {
+    for (i, &first) in EVENTS.iter().enumerate() {
+        for &second in &EVENTS[i + 1..] {
+            for (a, b) in [(first, second), (second, first)] {
+                let mut events = EventMap::default();
+                events.insert(a, Duration::from_millis(10));
+                events.insert(b, Duration::from_millis(10));
+                assert_eq!(
+                    events.next_event(),
+                    Some((first, Duration::from_millis(10))),
+                    "{a:?} and {b:?} scheduled at the same time"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn earlier_events_come_first_regardless_of_kind() {
+    let mut events = EventMap::default();
+    events.insert(InternalEvent::LineClear, Duration::from_millis(20));
+    events.insert(InternalEvent::Countdown, Duration::from_millis(10));
+    assert_eq!(
+        events.next_event(),
+        Some((InternalEvent::Countdown, Duration::from_millis(10)))
+    );
+}
+
+#[test]
+fn simultaneous_inputs_are_handled_in_declaration_order() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    let handled = Rc::new(RefCell::new(Vec::new()));
+    let record = Rc::clone(&handled);
+    // SAFETY: Only observes which events are handled.
+    unsafe {
+        game.add_modifier(Box::new(move |_, _, _, _, point| {
+            if let ModifierPoint::BeforeEvent(event) = point {
+                record.borrow_mut().push(*event);
+            }
+        }));
+    }
+    game.update(None, Duration::from_millis(100)).unwrap();
+    handled.borrow_mut().clear();
+    let mut buttons_pressed = ButtonsPressed::default();
+    buttons_pressed[Button::RotateRight] = true;
+    buttons_pressed[Button::MoveLeft] = true;
+    buttons_pressed[Button::DropSoft] = true;
+    game.update(Some(buttons_pressed), Duration::from_millis(200))
+        .unwrap();
+    game.update(None, Duration::from_millis(200)).unwrap();
+    assert_eq!(
+        *handled.borrow(),
+        [
+            InternalEvent::SoftDrop,
+            InternalEvent::MoveSlow,
+            InternalEvent::Rotate(1)
+        ]
+    );
+}
@@ -0,0 +1,66 @@
+//! Checks that pieces placed with more inputs than necessary are counted as finesse faults.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Feedback, Game, GameConfig,
+    GameMode, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Plays one T-piece by tapping each of the given buttons once, then hard drops it and returns the
+/// finesse fault feedback emitted, if any.
+fn place(taps: &[Button]) -> (Game, Option<(u32, u32)>) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::T),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::finesse(10), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let mut feedback_events = Vec::new();
+    for &button in taps.iter().chain([&Button::DropHard]) {
+        let mut buttons_pressed = ButtonsPressed::default();
+        buttons_pressed[button] = true;
+        time += STEP;
+        feedback_events.extend(game.update(Some(buttons_pressed), time).unwrap());
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    let fault = feedback_events
+        .into_iter()
+        .find_map(|(_, feedback)| match feedback {
+            Feedback::FinesseFault {
+                inputs, optimal, ..
+            } => Some((inputs, optimal)),
+            _ => None,
+        });
+    (game, fault)
+}
+
+#[test]
+fn optimal_placement_is_no_fault() {
+    let (game, fault) = place(&[Button::MoveLeft, Button::RotateRight]);
+    assert_eq!(fault, None);
+    assert_eq!(game.state().finesse_faults, 0);
+    assert_eq!(game.state().pieces_played[Tetromino::T], 1);
+}
+
+#[test]
+fn redundant_rotations_are_a_fault() {
+    let (game, fault) = place(&[Button::RotateLeft, Button::RotateRight]);
+    assert_eq!(fault, Some((2, 0)));
+    assert_eq!(game.state().finesse_faults, 1);
+}
+
+#[test]
+fn tapping_to_the_wall_is_a_fault() {
+    // Holding 'move left' would shift the piece to the wall with a single input.
+    let (game, fault) = place(&[Button::MoveLeft, Button::MoveLeft, Button::MoveLeft]);
+    assert_eq!(fault, Some((3, 1)));
+    assert_eq!(game.state().finesse_faults, 1);
+}
@@ -0,0 +1,62 @@
+//! Checks that queued garbage is inserted below the stack once a piece locks, and only locks out if
+//! it pushes the stack into where pieces spawn.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, GameOver, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Builds a one-wide tower in `column` up to the skyline, queues `rows` garbage lines and locks an
+/// O-piece out of the way, returning the game right after.
+fn raise_tower(column: usize, rows: usize) -> Game {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::O),
+        ..GameConfig::default()
+    };
+    let skyline = config.skyline;
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        for line in &mut board[..skyline] {
+            line[column] = Some(NonZeroU32::MIN);
+        }
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.queue_garbage(rows, 9);
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::O,
+        orientation: Orientation::N,
+        position: (7, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::O] == 0 {
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    game
+}
+
+#[test]
+fn garbage_beside_spawn_continues() {
+    let game = raise_tower(0, 1);
+    assert_eq!(game.state().end, None);
+    assert_eq!(game.stack_height(), GameConfig::default().skyline + 1);
+}
+
+#[test]
+fn garbage_into_spawn_locks_out() {
+    let game = raise_tower(4, 1);
+    assert_eq!(game.state().end, Some(Err(GameOver::LockOut)));
+}
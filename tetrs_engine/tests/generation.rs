@@ -0,0 +1,206 @@
+//! Checks piece generators and their seeded sequences, the preview and inspection of upcoming
+//! pieces.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, rng::Pcg32, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+fn seven_bag_game() -> Game {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::seven_bag(),
+        ..GameConfig::default()
+    };
+    Game::with_config_and_seed(GameMode::zen(), config, 42)
+}
+
+#[test]
+fn upcoming_matches_spawned_pieces() {
+    let mut game = seven_bag_game();
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let upcoming = game.upcoming(10);
+    assert_eq!(upcoming.len(), 10);
+    assert_eq!(
+        game.upcoming(10),
+        upcoming,
+        "peeking should not advance the game"
+    );
+    let mut spawned = Vec::new();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    for pieces_locked in 1..=10 {
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        while game.state().pieces_played.iter().sum::<u32>() < pieces_locked
+            || game.state().active_piece_data.is_none()
+        {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        spawned.push(game.state().active_piece_data.unwrap().0.shape);
+    }
+    assert_eq!(spawned, upcoming);
+}
+
+#[test]
+fn bag_snapshot_tracks_remaining_pieces() {
+    let mut game = seven_bag_game();
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    // One piece is active and the preview is filled, all drawn from the first bag.
+    let drawn = 1 + game.state().next_pieces.len();
+    let snapshot = game.config().tetromino_generator.peek_state();
+    let bag_remaining = snapshot.bag_remaining.expect("generator should be a bag");
+    assert_eq!(bag_remaining.iter().sum::<u32>() as usize, 7 - drawn);
+    let total_probability = snapshot.probabilities.iter().sum::<f64>();
+    assert!((total_probability - 1.0).abs() < 1e-9);
+    for (remaining, probability) in bag_remaining.iter().zip(snapshot.probabilities) {
+        assert_eq!(*remaining == 0, probability == 0.0);
+    }
+}
+
+/// Draws many pieces from `n`-bags and checks that every bag contains exactly `n` of each piece type.
+fn check_bag_constraints(n: usize) {
+    let mut tetromino_generator = TetrominoGenerator::multi_bag(n);
+    let mut rng = Pcg32::new(7, Pcg32::DEFAULT_STREAM);
+    let pieces = tetromino_generator
+        .with_rng(&mut rng)
+        .take(7 * n * 1000)
+        .collect::<Vec<_>>();
+    for bag in pieces.chunks(7 * n) {
+        let mut counts = [0; 7];
+        for &tetromino in bag {
+            counts[tetromino] += 1;
+        }
+        assert_eq!(counts, [n; 7]);
+    }
+    // No piece type can be absent for longer than the end of one bag and the start of the next.
+    for tetromino in (0..7).map(|i| Tetromino::try_from(i).unwrap()) {
+        let positions = pieces
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &t)| (t == tetromino).then_some(i))
+            .collect::<Vec<_>>();
+        for pair in positions.windows(2) {
+            assert!(pair[1] - pair[0] <= 2 * (7 * n - n) + 1);
+        }
+    }
+}
+
+#[test]
+fn seven_bag_deals_every_piece_once() {
+    check_bag_constraints(1);
+}
+
+#[test]
+fn fourteen_bag_deals_every_piece_twice() {
+    check_bag_constraints(2);
+}
+
+#[test]
+fn bag_with_multiplicity_matches_multi_bag() {
+    let deal = |mut tetromino_generator: TetrominoGenerator| {
+        let mut rng = Pcg32::new(7, Pcg32::DEFAULT_STREAM);
+        tetromino_generator
+            .with_rng(&mut rng)
+            .take(28)
+            .collect::<Vec<_>>()
+    };
+    let multiplicity = NonZeroU32::new(2).unwrap();
+    assert_eq!(
+        deal(TetrominoGenerator::bag(multiplicity)),
+        deal(TetrominoGenerator::multi_bag(2))
+    );
+    assert_eq!(
+        deal(TetrominoGenerator::bag(NonZeroU32::MIN)),
+        deal(TetrominoGenerator::seven_bag())
+    );
+}
+
+/// Counts how often a piece is immediately followed by the same piece over many draws.
+fn back_to_back_repeats(mut tetromino_generator: TetrominoGenerator) -> usize {
+    let mut rng = Pcg32::new(7, Pcg32::DEFAULT_STREAM);
+    let pieces = tetromino_generator
+        .with_rng(&mut rng)
+        .take(70_000)
+        .collect::<Vec<_>>();
+    pieces.windows(2).filter(|pair| pair[0] == pair[1]).count()
+}
+
+#[test]
+fn nes_repeats_are_rare_but_possible() {
+    let nes_repeats = back_to_back_repeats(TetrominoGenerator::nes());
+    let uniform_repeats = back_to_back_repeats(TetrominoGenerator::uniform());
+    // Expected are about `70_000 / 28 = 2_500` repeats for NES and `70_000 / 7 = 10_000` for uniform.
+    assert!(nes_repeats > 0);
+    assert!(
+        nes_repeats * 2 < uniform_repeats,
+        "{nes_repeats} NES vs. {uniform_repeats} uniform repeats"
+    );
+}
+
+#[test]
+fn preview_count_changes_take_effect_immediately() {
+    let mut game = seven_bag_game();
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    assert_eq!(game.state().next_pieces.len(), 1);
+    let shown = game.state().next_pieces[0];
+    game.set_preview_count(5);
+    assert_eq!(game.state().next_pieces.len(), 5);
+    assert_eq!(game.state().next_pieces[0], shown);
+    let grown = game.state().next_pieces.clone();
+    game.set_preview_count(2);
+    assert_eq!(
+        game.state().next_pieces,
+        grown.range(..2).copied().collect::<Vec<_>>()
+    );
+}
+
+/// Draws the first pieces of a generator seeded by a fixed [`Pcg32`], by their names.
+fn first_pieces(mut tetromino_generator: TetrominoGenerator) -> String {
+    let mut rng = Pcg32::new(42, Pcg32::DEFAULT_STREAM);
+    tetromino_generator
+        .with_rng(&mut rng)
+        .take(14)
+        .map(|tetromino| tetromino.name_char())
+        .collect()
+}
+
+#[test]
+fn seeded_piece_sequences_never_change() {
+    let sequences = [
+        TetrominoGenerator::uniform(),
+        TetrominoGenerator::seven_bag(),
+        TetrominoGenerator::Recency {
+            last_generated: [0, 1, 2, 3, 4, 5, 6],
+        },
+        TetrominoGenerator::total_relative(),
+        TetrominoGenerator::nes(),
+    ]
+    .map(first_pieces);
+    assert_eq!(
+        sequences,
+        [
+            "TIIIOOTSZSOZJO",
+            "TSJILOZSZITOJL",
+            "TZJSLOITJSZLOT",
+            "IOTSTIZLJSZJSL",
+            "SJSLJTIOTOLOOT",
+        ]
+    );
+}
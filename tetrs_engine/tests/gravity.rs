@@ -0,0 +1,111 @@
+//! Checks that pieces fall according to [`GameConfig::gravity_curve`], unless 20G applies, as it does
+//! right away in [`GameMode::death`] together with a short lock delay.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{Game, GameConfig, GameMode, GravityCurve, TwentyG};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Returns how long the first piece of a level 1 game takes to fall by one row, up to the step size
+/// used to advance the game.
+fn fall_time(config: GameConfig) -> Duration {
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    let mut fall_times = Vec::new();
+    let mut last_position = None;
+    while fall_times.len() < 2 {
+        time += STEP;
+        game.update(None, time).unwrap();
+        let position = game
+            .state()
+            .active_piece_data
+            .map(|(piece, _)| piece.position);
+        if last_position.is_some() && position != last_position {
+            fall_times.push(time);
+        }
+        last_position = position;
+    }
+    fall_times[1] - fall_times[0]
+}
+
+#[test]
+fn guideline_level_1_falls_once_per_second() {
+    let fall_time = fall_time(GameConfig::default());
+    assert!(fall_time.abs_diff(Duration::from_secs(1)) < STEP);
+}
+
+#[test]
+fn custom_curve_is_used() {
+    let config = GameConfig {
+        gravity_curve: GravityCurve::Custom(|_level| Duration::from_millis(100)),
+        ..GameConfig::default()
+    };
+    let fall_time = fall_time(config);
+    assert!(fall_time.abs_diff(Duration::from_millis(100)) < STEP);
+}
+
+#[test]
+fn table_curve_keeps_its_last_entry() {
+    let curve = GravityCurve::Table(vec![Duration::from_millis(300), Duration::from_millis(200)]);
+    let level = |level| NonZeroU32::new(level).unwrap();
+    assert_eq!(curve.drop_delay(level(1)), Duration::from_millis(300));
+    assert_eq!(curve.drop_delay(level(2)), Duration::from_millis(200));
+    assert_eq!(curve.drop_delay(level(30)), Duration::from_millis(200));
+    assert_eq!(
+        GravityCurve::Table(Vec::new()).drop_delay(level(3)),
+        Game::guideline_drop_delay(level(3))
+    );
+
+    let config = GameConfig {
+        gravity_curve: curve,
+        ..GameConfig::default()
+    };
+    let fall_time = fall_time(config);
+    assert!(fall_time.abs_diff(Duration::from_millis(300)) < STEP);
+}
+
+#[test]
+fn tgm_curve_speeds_up_to_twenty_g() {
+    let level = |level| NonZeroU32::new(level).unwrap();
+    let drop_delay = |n| GravityCurve::Tgm.drop_delay(level(n));
+    assert!(drop_delay(1) > Duration::from_secs(1));
+    assert!(drop_delay(20) < drop_delay(10));
+    // TGM's gravity drops back down at internal level 200.
+    assert_eq!(drop_delay(21), drop_delay(1));
+    assert_eq!(drop_delay(51), Duration::from_nanos(833_333));
+    assert_eq!(drop_delay(100), drop_delay(51));
+}
+
+#[test]
+fn twenty_g_overrides_curve() {
+    let config = GameConfig {
+        gravity_curve: GravityCurve::Table(vec![Duration::from_secs(10)]),
+        twenty_g: TwentyG::Always,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    time += STEP;
+    game.update(None, time).unwrap();
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    assert_eq!(piece.well_piece(&game.state().board), piece);
+}
+
+#[test]
+fn death_mode_lands_instantly_and_locks_quickly() {
+    let mut game = Game::with_seed(GameMode::death(), 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let (piece, locking_data) = game.state().active_piece_data.unwrap();
+    assert_eq!(piece, piece.well_piece(&game.state().board));
+    assert!(locking_data.touches_ground);
+    // The lock delay at level 26 is 195ms, down from 500ms at level 1.
+    game.update(None, Duration::from_millis(190)).unwrap();
+    assert_eq!(game.state().pieces_played[piece.shape], 0);
+    game.update(None, Duration::from_millis(200)).unwrap();
+    assert_eq!(game.state().pieces_played[piece.shape], 1);
+}
@@ -0,0 +1,57 @@
+//! Checks that line clears extend the time limit of [`GameMode::increment`].
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback, Game,
+    GameConfig, GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn single_extends_time_limit() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::increment(), config, |board| {
+        // A bottom row missing its four leftmost tiles, with one tile above so the single
+        // does not clear the board.
+        board[0][4..].fill(Some(NonZeroU32::MIN));
+        board[1][Game::WIDTH - 1] = Some(NonZeroU32::MIN);
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    time += STEP;
+    feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    let time_bonuses = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::TimeBonus(time_bonus) => Some(time_bonus),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(time_bonuses, [Duration::from_secs(1)]);
+    assert_eq!(game.state().time_bonus, Duration::from_secs(1));
+
+    game.update(None, Duration::from_millis(60_500)).unwrap();
+    assert!(!game.ended());
+    game.update(None, Duration::from_millis(61_100)).unwrap();
+    assert_eq!(game.state().end, Some(Ok(())));
+}
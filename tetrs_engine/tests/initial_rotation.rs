@@ -0,0 +1,69 @@
+//! Checks that rotation buttons held while a piece spawns rotate it right away ('IRS'), if enabled,
+//! and that a queued initial rotation takes their place.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Game, GameConfig, GameMode,
+    Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Holds the given button (if any) from the start countdown until the first T-piece has spawned,
+/// returning its orientation.
+fn spawn_holding(
+    held: Option<Button>,
+    initial_rotation: bool,
+    queued_rotation: Option<i32>,
+) -> Orientation {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::T),
+        initial_rotation,
+        start_countdown: Duration::from_millis(100),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    if let Some(right_turns) = queued_rotation {
+        game.queue_initial_rotation(right_turns);
+    }
+    let mut buttons_pressed = ButtonsPressed::default();
+    if let Some(button) = held {
+        buttons_pressed[button] = true;
+    }
+    let mut time = Duration::ZERO;
+    game.update(Some(buttons_pressed), time).unwrap();
+    assert!(game.state().active_piece_data.is_none());
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    piece.orientation
+}
+
+#[test]
+fn held_rotation_turns_spawned_piece() {
+    assert_eq!(
+        spawn_holding(Some(Button::RotateRight), true, None),
+        Orientation::E
+    );
+}
+
+#[test]
+fn held_rotation_is_ignored_if_disabled() {
+    assert_eq!(
+        spawn_holding(Some(Button::RotateRight), false, None),
+        Orientation::N
+    );
+}
+
+#[test]
+fn queued_rotation_overrides_held_buttons() {
+    assert_eq!(
+        spawn_holding(Some(Button::RotateLeft), true, Some(1)),
+        Orientation::E
+    );
+    assert_eq!(spawn_holding(None, true, Some(1)), Orientation::E);
+    assert_eq!(spawn_holding(None, false, Some(1)), Orientation::E);
+}
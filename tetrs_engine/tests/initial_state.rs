@@ -0,0 +1,49 @@
+//! Checks that a game can be started from a state close to the end of a game.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn sprint_one_line_away_is_won_by_a_single_clear() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::sprint(NonZeroU32::MIN), config, 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let mut state = game.state().clone();
+    state.lines_cleared = 39;
+    state.board[0][4..].fill(Some(NonZeroU32::MIN));
+    let (_, locking_data) = state.active_piece_data.unwrap();
+    state.active_piece_data = Some((
+        ActivePiece {
+            shape: Tetromino::I,
+            orientation: Orientation::N,
+            position: (0, 10),
+        },
+        locking_data,
+    ));
+
+    let mut game = Game::with_initial_state(
+        GameMode::sprint(NonZeroU32::MIN),
+        game.config().clone(),
+        state,
+    )
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    let mut time = game.state().time + STEP;
+    game.update(Some(drop), time).unwrap();
+    while !game.ended() {
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    assert_eq!(game.state().end, Some(Ok(())));
+    assert_eq!(game.state().lines_cleared, 40);
+}
@@ -0,0 +1,122 @@
+//! Checks that each invariant violation of a corrupted game state is reported, and that a preview
+//! shorter than configured is not one.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{Button, ButtonsPressed, Game, GameConfig, GameMode, GameOver, GameState};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Returns a valid state of a game with a piece in play, along with its config.
+fn running_state() -> (GameConfig, GameState) {
+    let mut game = Game::with_config_and_seed(GameMode::zen(), GameConfig::default(), 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    (game.config().clone(), game.state().clone())
+}
+
+/// Returns the reported invariant violation after corrupting a valid game.
+fn violation(corrupt: impl FnOnce(&mut GameConfig, &mut GameState)) -> String {
+    let (mut config, mut state) = running_state();
+    corrupt(&mut config, &mut state);
+    match Game::with_initial_state(GameMode::zen(), config, state) {
+        Ok(_) => panic!("corrupted state was accepted"),
+        Err(violation) => violation,
+    }
+}
+
+#[test]
+fn valid_state_is_accepted() {
+    let (config, state) = running_state();
+    assert!(Game::with_initial_state(GameMode::zen(), config, state).is_ok());
+}
+
+#[test]
+fn wrong_board_height_is_reported() {
+    let msg = violation(|_, state| {
+        state.board.pop();
+    });
+    assert_eq!(msg, "board height is 26 instead of 27");
+}
+
+#[test]
+fn wrong_line_width_is_reported() {
+    let msg = violation(|_, state| {
+        state.board[3] = vec![None; 9].into_boxed_slice();
+    });
+    assert_eq!(msg, "line 3 has width 9 instead of 10");
+}
+
+#[test]
+fn skyline_above_board_is_reported() {
+    let msg = violation(|config, _| {
+        config.skyline = config.height + 1;
+    });
+    assert_eq!(msg, "skyline 28 lies above board height 27");
+}
+
+#[test]
+fn missing_events_are_reported() {
+    let msg = violation(|_, state| {
+        state.events.clear();
+    });
+    assert_eq!(msg, "game has not ended but no events are scheduled");
+}
+
+#[test]
+fn past_events_are_reported() {
+    let msg = violation(|_, state| {
+        state.time += Duration::from_secs(60);
+    });
+    assert!(msg.contains("lies before current game time"), "{msg}");
+}
+
+#[test]
+fn overlapping_active_piece_is_reported() {
+    let msg = violation(|_, state| {
+        let (active_piece, _) = state.active_piece_data.unwrap();
+        let ((x, y), _) = active_piece.tiles()[0];
+        state.board[y][x] = Some(NonZeroU32::MIN);
+    });
+    assert!(msg.contains("does not fit onto the board"), "{msg}");
+}
+
+#[test]
+fn short_preview_is_accepted() {
+    let (config, mut state) = running_state();
+    state.next_pieces.clear();
+    assert!(Game::with_initial_state(GameMode::zen(), config, state).is_ok());
+}
+
+#[test]
+fn preview_raised_mid_game_fills_up_at_next_spawn() {
+    let mut game = Game::with_config_and_seed(GameMode::zen(), GameConfig::default(), 1);
+    let mut time = Duration::from_secs(1);
+    game.update(None, time).unwrap();
+    game.config_mut().preview_count = 5;
+    time += Duration::from_millis(100);
+    game.update(None, time).unwrap();
+    assert_eq!(game.state().next_pieces.len(), 1);
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played.iter().sum::<u32>() == 0
+        || game.state().active_piece_data.is_none()
+    {
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    assert_eq!(game.state().next_pieces.len(), 5);
+}
+
+#[test]
+fn ended_game_needs_no_events() {
+    let (config, mut state) = running_state();
+    state.end = Some(Err(GameOver::Forfeit));
+    state.events.clear();
+    assert!(Game::with_initial_state(GameMode::zen(), config, state).is_ok());
+}
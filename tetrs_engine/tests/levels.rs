@@ -0,0 +1,72 @@
+//! Checks that the level increments whenever a multiple of 10 cleared lines is passed.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback,
+    GameConfig, GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Clears a quad from 38 to 42 lines in the given mode, returning the level before and after as well
+/// as all levels announced by [`Feedback::LevelUp`].
+fn quad_across_milestone(game_mode: GameMode) -> (NonZeroU32, NonZeroU32, Vec<NonZeroU32>) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_state(game_mode, config, |state| {
+        for line in &mut state.board[0..4] {
+            for tile in &mut line[1..] {
+                *tile = Some(NonZeroU32::MIN);
+            }
+        }
+        state.lines_cleared = 38;
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let level_before = game.state().level;
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::E,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    while game.state().lines_cleared == 38 || game.state().active_piece_data.is_none() {
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    assert_eq!(game.state().lines_cleared, 42);
+    let level_ups = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::LevelUp(level) => Some(level),
+            _ => None,
+        })
+        .collect();
+    (level_before, game.state().level, level_ups)
+}
+
+#[test]
+fn quad_across_milestone_increments_level_once() {
+    let (level_before, level_after, level_ups) = quad_across_milestone(GameMode::marathon());
+    assert_eq!(level_after, level_before.saturating_add(1));
+    assert_eq!(level_ups, [level_after]);
+}
+
+#[test]
+fn fixed_level_modes_never_level_up() {
+    let (level_before, level_after, level_ups) = quad_across_milestone(GameMode::zen());
+    assert_eq!(level_after, level_before);
+    assert_eq!(level_ups, []);
+}
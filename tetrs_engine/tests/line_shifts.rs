@@ -0,0 +1,59 @@
+//! Checks that line clears report how far each surviving row shifts down.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback,
+    GameConfig, GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn clearing_rows_zero_and_two_shifts_rows_above() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let height = config.height;
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        // Rows 0 and 2 are completed by a vertical I-piece in the left column, rows 1 and
+        // 3 are not.
+        board[0][1..].fill(Some(NonZeroU32::MIN));
+        board[1][5] = Some(NonZeroU32::MIN);
+        board[2][1..].fill(Some(NonZeroU32::MIN));
+        board[3][5] = Some(NonZeroU32::MIN);
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::E,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::I] == 0 {
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    let line_shifts = feedback_events
+        .into_iter()
+        .find_map(|(_, feedback)| match feedback {
+            Feedback::LineShifts(line_shifts) => Some(line_shifts),
+            _ => None,
+        })
+        .unwrap();
+    let expected = std::iter::once((1, 1))
+        .chain((3..height).map(|y| (y, 2)))
+        .collect::<Vec<_>>();
+    assert_eq!(line_shifts, expected);
+}
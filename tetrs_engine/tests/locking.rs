@@ -0,0 +1,138 @@
+//! Checks lock delay resets, which can be capped to prevent stalling indefinitely ('infinity') and
+//! are not granted for rotating an O-piece in place, the total ground time of a piece, the
+//! configured lock delay, and locking instantly on landing.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, Button, ButtonsPressed, Game, GameConfig, GameMode,
+    Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(50);
+
+/// Drops the first piece of the given shape to the floor and keeps rotating it, returning after how
+/// many rotations it locked, if it did so within `max_rotations`.
+fn rotations_until_lock(
+    shape: Tetromino,
+    lock_delay_max_resets: Option<u32>,
+    max_rotations: u32,
+) -> Option<u32> {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(shape),
+        // Make sure only the reset cap can force the piece to lock.
+        ground_time_max: Duration::from_secs(60 * 60),
+        lock_delay_max_resets,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let (piece, _) = game.state().active_piece_data.unwrap();
+    game.set_active_piece(piece.well_piece(&game.state().board))
+        .unwrap();
+    let mut rotate = ButtonsPressed::default();
+    rotate[Button::RotateRight] = true;
+    for rotations in 0..max_rotations {
+        if game.state().pieces_played[shape] > 0 {
+            return Some(rotations);
+        }
+        time += STEP;
+        game.update(Some(rotate), time).unwrap();
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    None
+}
+
+#[test]
+fn unlimited_resets_allow_stalling() {
+    assert_eq!(rotations_until_lock(Tetromino::T, None, 200), None);
+}
+
+#[test]
+fn capped_resets_force_lock() {
+    let rotations =
+        rotations_until_lock(Tetromino::T, Some(15), 200).expect("piece should have locked");
+    // Every rotation refreshes the lock timer until the cap is reached, after which the piece locks
+    // once the (unrefreshed) lock delay runs out.
+    assert!(
+        (15..=30).contains(&rotations),
+        "locked after {rotations} rotations"
+    );
+}
+
+#[test]
+fn rotating_o_piece_in_place_does_not_reset_lock_delay() {
+    // The lock delay at level 1 runs out after five rotations.
+    let rotations =
+        rotations_until_lock(Tetromino::O, None, 200).expect("piece should have locked");
+    assert!(rotations <= 5, "locked after {rotations} rotations");
+}
+
+#[test]
+fn ground_time_runs_out_while_grounded() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let (spawned, locking_data) = game.state().active_piece_data.unwrap();
+    assert!(!locking_data.touches_ground);
+    assert_eq!(locking_data.time_until_forced_lock(game.state().time), None);
+
+    game.set_active_piece(spawned.well_piece(&game.state().board))
+        .unwrap();
+    game.update(None, Duration::from_millis(200)).unwrap();
+    let (_, locking_data) = game.state().active_piece_data.unwrap();
+    assert!(locking_data.touches_ground);
+    assert_eq!(
+        locking_data.time_until_forced_lock(game.state().time),
+        Some(game.config().ground_time_max - Duration::from_millis(200))
+    );
+}
+
+#[test]
+fn lock_delay_curve_sets_lock_delay() {
+    let config = GameConfig {
+        lock_delay_curve: |_level| Duration::from_millis(100),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let (spawned, _) = game.state().active_piece_data.unwrap();
+    game.set_active_piece(spawned.well_piece(&game.state().board))
+        .unwrap();
+    game.update(None, Duration::from_millis(50)).unwrap();
+    assert_eq!(game.lock_meter(), Some(0.5));
+    game.update(None, Duration::from_millis(100)).unwrap();
+    assert_eq!(game.state().pieces_played[spawned.shape], 1);
+}
+
+/// Lets the first piece fall onto the floor from one row above it, returning whether it locked as
+/// soon as it landed.
+fn locks_on_landing(instant_lock: bool) -> bool {
+    let config = GameConfig {
+        instant_lock,
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let (spawned, _) = game.state().active_piece_data.unwrap();
+    let mut piece = spawned.well_piece(&game.state().board);
+    piece.position.1 += 1;
+    game.set_active_piece(piece).unwrap();
+    // The piece falls by one row after one drop delay (one second at level 1).
+    game.update(None, Duration::from_secs(1)).unwrap();
+    game.state().pieces_played[spawned.shape] == 1
+}
+
+#[test]
+fn instant_lock_locks_on_landing() {
+    assert!(locks_on_landing(true));
+}
+
+#[test]
+fn landing_piece_waits_for_lock_delay() {
+    assert!(!locks_on_landing(false));
+}
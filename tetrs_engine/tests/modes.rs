@@ -0,0 +1,196 @@
+//! Plays each built-in game mode to completion with a seeded game and a fixed input script.
+//!
+//! The 'player' puts each piece at the best spot according to a simple stacking heuristic and
+//! hard drops it, then releases all buttons. Since the game is seeded and all update times are
+//! fixed, every run ends in exactly the same state, which is compared against golden values below.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    analysis, ActivePiece, Board, Button, ButtonsPressed, Game, GameMode, GameOver, Orientation,
+};
+
+const SEED: u64 = 42;
+const STEP: Duration = Duration::from_millis(50);
+const TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Summary of a finished game that is compared against golden values.
+#[derive(PartialEq, Eq, Debug)]
+struct Outcome {
+    end: Result<(), GameOver>,
+    pieces: u32,
+    lines: usize,
+    level: u32,
+    score: u32,
+}
+
+/// Returns the placement of the active piece which leaves the 'best' board, if any.
+///
+/// Only placements reachable by dropping the piece straight down are considered; boards are rated
+/// by a weighted sum of their aggregate height, holes and bumpiness, and the lines cleared.
+fn best_placement(game: &Game) -> Option<ActivePiece> {
+    let (active_piece, _) = game.state().active_piece_data?;
+    let board = &game.state().board;
+    let orientations = [
+        Orientation::N,
+        Orientation::E,
+        Orientation::S,
+        Orientation::W,
+    ];
+    orientations
+        .into_iter()
+        .flat_map(|orientation| {
+            (0..Game::WIDTH).filter_map(move |x| {
+                let piece = ActivePiece {
+                    shape: active_piece.shape,
+                    orientation,
+                    position: (x, Game::SKYLINE),
+                };
+                piece.fits(board).then(|| piece.well_piece(board))
+            })
+        })
+        .min_by(|piece1, piece2| cost(board, piece1).total_cmp(&cost(board, piece2)))
+}
+
+/// Rates the board resulting from locking the given piece (lower is better).
+fn cost(board: &Board, piece: &ActivePiece) -> f64 {
+    let mut board = board.clone();
+    for ((x, y), tile_type_id) in piece.tiles() {
+        board[y][x] = Some(tile_type_id);
+    }
+    let lines_before = board.len();
+    board.retain(|line| line.iter().any(|cell| cell.is_none()));
+    let lines_cleared = lines_before - board.len();
+    let aggregate_height: usize = analysis::column_heights(&board).iter().sum();
+    let holes = analysis::count_holes(&board);
+    let bumpiness = analysis::aggregate_bumpiness(&board);
+    0.51 * aggregate_height as f64 + 0.36 * holes as f64 + 0.18 * bumpiness as f64
+        - 0.76 * lines_cleared as f64
+}
+
+/// Plays the given mode until it ends, forfeiting once `forfeit_after` pieces have been played.
+fn play(game_mode: GameMode, forfeit_after: Option<u32>) -> Outcome {
+    let mut game = Game::with_seed(game_mode, SEED);
+    let mut time = Duration::ZERO;
+    let mut hard_drop_held = false;
+    while !game.ended() {
+        assert!(time < TIMEOUT, "game did not end within {TIMEOUT:?}");
+        time += STEP;
+        let new_button_state = if hard_drop_held {
+            hard_drop_held = false;
+            Some(ButtonsPressed::default())
+        } else if let Some(piece) = best_placement(&game) {
+            game.set_active_piece(piece).unwrap();
+            hard_drop_held = true;
+            let mut buttons_pressed = ButtonsPressed::default();
+            buttons_pressed[Button::DropHard] = true;
+            Some(buttons_pressed)
+        } else {
+            None
+        };
+        game.update(new_button_state, time).unwrap();
+        assert_eq!(game.validate_invariants(), Ok(()));
+        let pieces = game.state().pieces_played.iter().sum::<u32>();
+        if forfeit_after.is_some_and(|limit| limit <= pieces) {
+            game.forfeit();
+        }
+    }
+    let state = game.state();
+    Outcome {
+        end: state.end.unwrap(),
+        pieces: state.pieces_played.iter().sum(),
+        lines: state.lines_cleared,
+        level: state.level.get(),
+        score: state.score,
+    }
+}
+
+#[test]
+fn marathon() {
+    let outcome = play(GameMode::marathon(), None);
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Ok(()),
+            pieces: 487,
+            lines: 190,
+            level: 20,
+            score: 5180,
+        }
+    );
+}
+
+#[test]
+fn sprint() {
+    let outcome = play(GameMode::sprint(NonZeroU32::MIN), None);
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Ok(()),
+            pieces: 107,
+            lines: 40,
+            level: 1,
+            score: 1110,
+        }
+    );
+}
+
+#[test]
+fn ultra() {
+    let outcome = play(GameMode::ultra(NonZeroU32::MIN), None);
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Ok(()),
+            pieces: 812,
+            lines: 310,
+            level: 1,
+            score: 8090,
+        }
+    );
+}
+
+#[test]
+fn master() {
+    let outcome = play(GameMode::master(), None);
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Ok(()),
+            pieces: 777,
+            lines: 300,
+            level: 50,
+            score: 7870,
+        }
+    );
+}
+
+#[test]
+fn death() {
+    let outcome = play(GameMode::death(), None);
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Ok(()),
+            pieces: 107,
+            lines: 40,
+            level: 30,
+            score: 1110,
+        }
+    );
+}
+
+#[test]
+fn zen() {
+    let outcome = play(GameMode::zen(), Some(100));
+    assert_eq!(
+        outcome,
+        Outcome {
+            end: Err(GameOver::Forfeit),
+            pieces: 100,
+            lines: 36,
+            level: 1,
+            score: 1040,
+        }
+    );
+}
@@ -0,0 +1,46 @@
+//! Checks that observers see every event and each feedback exactly once, without modifying the game.
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use tetrs_engine::{
+    Button, ButtonsPressed, Feedback, Game, GameMode, InternalEvent, ModifierPoint,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn observer_sees_spawns_once() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    // Counts spawn events and spawn feedback seen.
+    let spawns = Rc::new(RefCell::new((0, 0)));
+    let observed_spawns = Rc::clone(&spawns);
+    game.add_observer(Box::new(move |_, _, state, feedback_events, point| {
+        let mut spawns = observed_spawns.borrow_mut();
+        if *point == ModifierPoint::AfterEvent(InternalEvent::Spawn) {
+            assert!(state.active_piece_data.is_some());
+            spawns.0 += 1;
+        }
+        for (_, feedback) in feedback_events {
+            if matches!(feedback, Feedback::Spawn { .. }) {
+                spawns.1 += 1;
+            }
+        }
+    }));
+    let mut time = Duration::ZERO;
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    for _ in 0..5 {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    assert_eq!(game.state().pieces_played.iter().sum::<u32>(), 5);
+    let spawns = *spawns.borrow();
+    assert!(spawns.0 >= 5);
+    assert_eq!(spawns.0, spawns.1);
+}
@@ -0,0 +1,36 @@
+//! Checks that pausing a game freezes its timeline, so pending events keep their order and timing.
+
+use std::time::Duration;
+
+use tetrs_engine::{ActivePiece, Game, GameMode, Orientation, Tetromino};
+
+#[test]
+fn pause_during_lock_delay() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    game.update(None, Duration::ZERO).unwrap();
+    let piece = ActivePiece {
+        shape: Tetromino::O,
+        orientation: Orientation::N,
+        position: (4, 0),
+    };
+    game.set_active_piece(piece).unwrap();
+    // Half of the 500ms lock delay passes before pausing.
+    game.update(None, Duration::from_millis(250)).unwrap();
+    game.pause(Duration::from_millis(250));
+    assert!(game.is_paused());
+
+    // Nothing happens while paused, no matter how much time passes.
+    let feedback_events = game.update(None, Duration::from_secs(10)).unwrap();
+    assert!(feedback_events.is_empty());
+    assert_eq!(game.state().time, Duration::from_millis(250));
+    assert_eq!(game.lock_meter(), Some(0.5));
+
+    // After resuming, the remaining half of the lock delay still has to pass.
+    game.resume(Duration::from_secs(10));
+    assert!(!game.is_paused());
+    game.update(None, Duration::from_millis(10_200)).unwrap();
+    assert_eq!(game.state().time, Duration::from_millis(450));
+    assert_eq!(game.state().pieces_played[Tetromino::O], 0);
+    game.update(None, Duration::from_millis(10_250)).unwrap();
+    assert_eq!(game.state().pieces_played[Tetromino::O], 1);
+}
@@ -0,0 +1,144 @@
+//! Checks that perfect clears are counted, reported and end [`GameMode::perfect_clear`] once enough
+//! are made, and when [`Game::perfect_clear_possible`] finds one within reach.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback, Game,
+    GameConfig, GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Fills the bottom row except for its four leftmost cells, then hard drops a flat I-piece into the
+/// gap, returning the game once the next piece spawned or the game ended.
+fn flat_i_perfect_clear(game_mode: GameMode) -> Game {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(game_mode, config, |board| {
+        board[0][4..].fill(Some(NonZeroU32::MIN));
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    game.update(Some(drop), time).unwrap();
+    while !game.ended()
+        && (game.state().pieces_played[Tetromino::I] == 0
+            || game.state().active_piece_data.is_none())
+    {
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    game
+}
+
+#[test]
+fn perfect_clears_are_counted() {
+    let game = flat_i_perfect_clear(GameMode::zen());
+    assert_eq!(game.state().perfect_clears, 1);
+    assert!(game.state().board.iter().flatten().all(Option::is_none));
+    assert_eq!(game.state().end, None);
+}
+
+#[test]
+fn perfect_clear_mode_is_won() {
+    let game = flat_i_perfect_clear(GameMode::perfect_clear(1));
+    assert_eq!(game.state().end, Some(Ok(())));
+    assert_eq!(
+        game.mode().limits.describe_progress(game.state()),
+        ["1/1 PCs"]
+    );
+}
+
+/// Returns a game whose board has the given bottom rows (from top to bottom, `#` marking filled
+/// cells), with only pieces of the given shape in play.
+fn game_with_board(shape: Tetromino, rows: &[&str]) -> Game {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(shape),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        for (y, row) in rows.iter().rev().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c == '#' {
+                    board[y][x] = Some(NonZeroU32::MIN);
+                }
+            }
+        }
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game
+}
+
+#[test]
+fn perfect_clear_within_one_piece() {
+    let game = game_with_board(Tetromino::I, &["....######"]);
+    assert!(!game.perfect_clear_possible(0));
+    assert!(game.perfect_clear_possible(1));
+}
+
+#[test]
+fn perfect_clear_within_two_pieces() {
+    let game = game_with_board(Tetromino::I, &["........##"]);
+    assert!(!game.perfect_clear_possible(1));
+    assert!(game.perfect_clear_possible(2));
+}
+
+#[test]
+fn perfect_clear_impossible_with_wrong_pieces() {
+    // Two O-pieces fill the gap, but leave their upper halves behind.
+    let game = game_with_board(Tetromino::O, &["....######"]);
+    assert!(!game.perfect_clear_possible(2));
+}
+
+#[test]
+fn tetris_perfect_clear_is_reported_once() {
+    #[rustfmt::skip]
+    let mut game = game_with_board(Tetromino::I, &[
+        ".#########",
+        ".#########",
+        ".#########",
+        ".#########",
+    ]);
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::E,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    let mut time = game.state().time + STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::I] == 0 {
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    let perfect_clears = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::PerfectClear { lines } => Some(lines),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(perfect_clears, [4]);
+}
@@ -0,0 +1,37 @@
+//! Checks that [`Game::suggest_placement`] picks the placement leaving the cleanest stack.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{piece_generation::TetrominoGenerator, GameConfig, GameMode, Tetromino};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn i_piece_is_suggested_into_the_well() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        // Four rows with a well in the rightmost column.
+        for line in &mut board[..4] {
+            line[..9].fill(Some(NonZeroU32::MIN));
+        }
+    });
+    assert_eq!(game.suggest_placement(), None);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let suggestion = game.suggest_placement().unwrap();
+    let mut tiles = suggestion
+        .tiles()
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+    tiles.sort_unstable();
+    assert_eq!(tiles, [(9, 0), (9, 1), (9, 2), (9, 3)]);
+}
@@ -0,0 +1,79 @@
+//! Checks that rendering the board draws the active piece and its ghost without touching the game,
+//! and that tile ids map back to the tetrominos they belong to.
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Game, GameConfig, GameMode, Orientation,
+    Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Returns a game with a horizontal I-piece hovering above an empty board.
+fn game_with_piece() -> (Game, ActivePiece) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    let piece = ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (3, 10),
+    };
+    game.set_active_piece(piece).unwrap();
+    (game, piece)
+}
+
+#[test]
+fn render_board_draws_active_piece() {
+    let (game, piece) = game_with_piece();
+    let source_board = game.state().board.clone();
+    let rendered = game.render_board();
+    for ((x, y), tile_type_id) in piece.tiles() {
+        assert_eq!(rendered[y][x], Some(tile_type_id));
+        assert_eq!(game.state().board[y][x], None);
+    }
+    assert_eq!(game.state().board, source_board);
+    let drawn = rendered
+        .iter()
+        .flatten()
+        .filter(|tile| tile.is_some())
+        .count();
+    assert_eq!(drawn, 4);
+}
+
+#[test]
+fn render_board_with_ghost_draws_both() {
+    let (game, piece) = game_with_piece();
+    let ghost_tile = NonZeroU32::new(255).unwrap();
+    let rendered = game.render_board_with_ghost(Some(ghost_tile));
+    for ((x, y), tile_type_id) in piece.tiles() {
+        assert_eq!(rendered[y][x], Some(tile_type_id));
+    }
+    for ((x, y), _) in piece.ghost_tiles(&game.state().board) {
+        assert_eq!(y, 0);
+        assert_eq!(rendered[y][x], Some(ghost_tile));
+    }
+    assert!(game.state().board.iter().flatten().all(Option::is_none));
+}
+
+#[test]
+fn tile_type_ids_round_trip() {
+    for (i, name_char) in "OISZTLJ".chars().enumerate() {
+        let tetromino = Tetromino::try_from(i).unwrap();
+        assert_eq!(
+            Tetromino::from_tiletypeid(tetromino.tiletypeid()),
+            Some(tetromino)
+        );
+        assert_eq!(tetromino.name_char(), name_char);
+    }
+    assert_eq!(Tetromino::from_tiletypeid(Game::GARBAGE_TILE), None);
+    assert_eq!(Tetromino::from_tiletypeid(Game::WALL_TILE), None);
+}
@@ -0,0 +1,77 @@
+//! Checks that a recorded game survives serialization and replays to the exact same state.
+#![cfg(feature = "serde")]
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator,
+    replay::{Recorder, Replay},
+    Button, ButtonsPressed, GameConfig, GameMode, GameTime,
+};
+
+const STEP: Duration = Duration::from_millis(20);
+
+/// Taps the given button once, or just waits if there is none.
+fn tap(recorder: &mut Recorder, time: &mut GameTime, button: Option<Button>) {
+    if let Some(button) = button {
+        let mut pressed = ButtonsPressed::default();
+        pressed[button] = true;
+        *time += STEP;
+        recorder.update(Some(pressed), *time).unwrap();
+    }
+    *time += STEP;
+    recorder
+        .update(Some(ButtonsPressed::default()), *time)
+        .unwrap();
+}
+
+/// Plays a game of 40-Lines by placing each piece where [`Game::suggest_placement`] suggests.
+fn record_sprint() -> Replay {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::seven_bag(),
+        ..GameConfig::default()
+    };
+    let mut recorder = Recorder::new(GameMode::sprint(NonZeroU32::MIN), config, 7);
+    let mut time = Duration::ZERO;
+    while !recorder.game().ended() {
+        let game = recorder.game();
+        let button = match (game.state().active_piece_data, game.suggest_placement()) {
+            (Some((piece, locking_data)), Some(target)) if locking_data.lock_resets < 8 => {
+                if piece.orientation != target.orientation {
+                    Some(Button::RotateRight)
+                } else if piece.position.0 < target.position.0 {
+                    Some(Button::MoveRight)
+                } else if target.position.0 < piece.position.0 {
+                    Some(Button::MoveLeft)
+                } else {
+                    Some(Button::DropHard)
+                }
+            }
+            (Some(_), _) => Some(Button::DropHard),
+            (None, _) => None,
+        };
+        tap(&mut recorder, &mut time, button);
+    }
+    recorder.into_replay()
+}
+
+#[test]
+fn serialized_sprint_replays_identically() {
+    let replay = record_sprint();
+    let recorded = replay.play_to(GameTime::MAX).unwrap();
+    assert_eq!(recorded.state().end, Some(Ok(())));
+    assert!(recorded.state().lines_cleared >= 40);
+
+    let json = serde_json::to_string(&replay).unwrap();
+    let restored: Replay = serde_json::from_str(&json).unwrap();
+    let replayed = restored.play_to(GameTime::MAX).unwrap();
+    assert_eq!(replayed.state(), recorded.state());
+}
+
+#[test]
+fn inconsistent_replay_is_reported() {
+    let mut replay = record_sprint();
+    // An input going back in time cannot have been recorded.
+    replay.inputs.insert(10, (Duration::ZERO, None));
+    assert!(replay.play_to(GameTime::MAX).is_err());
+}
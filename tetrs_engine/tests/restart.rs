@@ -0,0 +1,104 @@
+//! Checks that restarting a game in place resets it to its very beginning, while clearing only the
+//! board keeps all statistics.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, Orientation, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Hard drops the given number of pieces, returning the shapes of all pieces that spawned.
+fn play(game: &mut Game, pieces: u32) -> Vec<Tetromino> {
+    let mut time = game.state().time;
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    let mut spawned = Vec::new();
+    for pieces_locked in 0..pieces {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        spawned.push(game.state().active_piece_data.unwrap().0.shape);
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        while game.state().pieces_played.iter().sum::<u32>() == pieces_locked {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+    }
+    spawned
+}
+
+#[test]
+fn restart_replays_seeded_game() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::seven_bag(),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::marathon(), config, 3);
+    let first_round = play(&mut game, 5);
+    assert!(game.state().board.iter().flatten().any(Option::is_some));
+
+    game.restart();
+    let state = game.state();
+    assert_eq!(state.time, Duration::ZERO);
+    assert_eq!(state.pieces_played, [0; 7]);
+    assert_eq!(state.score, 0);
+    assert!(state.next_pieces.is_empty());
+    assert!(state.active_piece_data.is_none());
+    assert!(state.board.iter().flatten().all(Option::is_none));
+    assert_eq!(state.board.len(), game.config().height);
+
+    let second_round = play(&mut game, 5);
+    assert_eq!(first_round, second_round);
+}
+
+#[test]
+fn clearing_board_keeps_stats() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        board[0][4..].fill(Some(NonZeroU32::MIN));
+        board[1][9] = Some(NonZeroU32::MIN);
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    // Clear the bottom row with a flat I-piece, leaving a single tile behind.
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::I] == 0 || game.state().active_piece_data.is_none()
+    {
+        time += STEP;
+        game.update(Some(ButtonsPressed::default()), time).unwrap();
+    }
+    let (score, lines_cleared) = (game.state().score, game.state().lines_cleared);
+    assert!(score > 0);
+    assert_eq!(lines_cleared, 1);
+    assert!(game.state().board.iter().flatten().any(Option::is_some));
+
+    game.clear_board_keep_stats();
+    assert!(game.state().board.iter().flatten().all(Option::is_none));
+    game.update(None, time).unwrap();
+    assert!(game.state().active_piece_data.is_some());
+    assert_eq!(game.state().score, score);
+    assert_eq!(game.state().lines_cleared, lines_cleared);
+    assert_eq!(game.state().pieces_played[Tetromino::I], 1);
+}
@@ -0,0 +1,128 @@
+//! Checks that the Super Rotation System resolves canonical kicks to their documented offsets.
+
+use std::num::NonZeroU32;
+
+use tetrs_engine::{
+    piece_rotation::{KickInfo, RotationSystem},
+    ActivePiece, Board, Coord, Game, GameConfig, Orientation, Tetromino,
+};
+
+fn board_with(cells: &[Coord]) -> Board {
+    let mut board = GameConfig::default().empty_board();
+    for &(x, y) in cells {
+        board[y][x] = Some(NonZeroU32::MIN);
+    }
+    board
+}
+
+#[test]
+fn t_spin_triple_uses_last_kick() {
+    // Three-deep T slot in column 4 with an opening to the right in the middle row, covered by a
+    // wall with a lip on the left.
+    let mut cells = vec![(4, 4)];
+    for x in 0..Game::WIDTH {
+        if x != 4 {
+            cells.push((x, 0));
+            cells.push((x, 2));
+        }
+        if x != 4 && x != 5 {
+            cells.push((x, 1));
+        }
+        if x <= 3 {
+            cells.push((x, 3));
+            cells.push((x, 4));
+        }
+    }
+    let board = board_with(&cells);
+    let t_piece = ActivePiece {
+        shape: Tetromino::T,
+        orientation: Orientation::N,
+        position: (4, 3),
+    };
+    assert!(t_piece.fits(&board));
+
+    let (rotated, kick) = RotationSystem::Super
+        .rotate_with_kick(&t_piece, &board, 1)
+        .unwrap();
+
+    // The fifth SRS kick, on top of the adjustment that keeps the piece centered.
+    assert_eq!(
+        kick,
+        KickInfo {
+            index: 4,
+            offset: (-1, -2),
+            centering: (1, -1),
+        }
+    );
+    assert_eq!(
+        rotated,
+        ActivePiece {
+            shape: Tetromino::T,
+            orientation: Orientation::E,
+            position: (4, 0),
+        }
+    );
+}
+
+#[test]
+fn i_piece_floor_kicks() {
+    let board = board_with(&[]);
+    let i_piece = ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (3, 0),
+    };
+
+    // Rotating right on the floor needs the fifth kick.
+    let (rotated_right, kick_right) = RotationSystem::Super
+        .rotate_with_kick(&i_piece, &board, 1)
+        .unwrap();
+    assert_eq!(
+        kick_right,
+        KickInfo {
+            index: 4,
+            offset: (1, 2),
+            centering: (2, -2),
+        }
+    );
+    assert_eq!(rotated_right.position, (6, 0));
+
+    // Rotating left on the floor needs the fourth kick.
+    let (rotated_left, kick_left) = RotationSystem::Super
+        .rotate_with_kick(&i_piece, &board, -1)
+        .unwrap();
+    assert_eq!(
+        kick_left,
+        KickInfo {
+            index: 3,
+            offset: (-1, 2),
+            centering: (1, -2),
+        }
+    );
+    assert_eq!(rotated_left.position, (3, 0));
+}
+
+#[test]
+fn half_turns_kick_upwards() {
+    // A T-piece flat on the floor cannot turn upside down in place.
+    let board = board_with(&[]);
+    let t_piece = ActivePiece {
+        shape: Tetromino::T,
+        orientation: Orientation::N,
+        position: (3, 0),
+    };
+
+    let (rotated, kick) = RotationSystem::Super
+        .rotate_with_kick(&t_piece, &board, 2)
+        .unwrap();
+
+    assert_eq!(
+        kick,
+        KickInfo {
+            index: 1,
+            offset: (0, 1),
+            centering: (0, -1),
+        }
+    );
+    assert_eq!(rotated.orientation, Orientation::S);
+}
@@ -0,0 +1,66 @@
+//! Checks that line clears are scored by [`GameConfig::scoring`], which defaults to
+//! [`ScoreEvent::default_score`].
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback,
+    GameConfig, GameMode, Orientation, ScoreEvent, SpinKind, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn accolade_reports_custom_score() {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::I),
+        scoring: |event| 1000 * event.lineclears,
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        board[0][4..].fill(Some(NonZeroU32::MIN));
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::N,
+        position: (0, 10),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    let mut feedback_events = game.update(Some(drop), time).unwrap();
+    while game.state().pieces_played[Tetromino::I] == 0 {
+        time += STEP;
+        feedback_events.extend(game.update(Some(ButtonsPressed::default()), time).unwrap());
+    }
+    let score_bonuses = feedback_events
+        .into_iter()
+        .filter_map(|(_, feedback)| match feedback {
+            Feedback::Accolade { score_bonus, .. } => Some(score_bonus),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(score_bonuses, [1000]);
+    assert_eq!(game.state().score, 1000);
+}
+
+#[test]
+fn default_score_handles_empty_event() {
+    let nothing = ScoreEvent {
+        lineclears: 0,
+        spin: SpinKind::None,
+        perfect_clear: false,
+        combo: 0,
+        back_to_back: 0,
+        level: NonZeroU32::MIN,
+    };
+    assert_eq!(nothing.default_score(), 0);
+}
@@ -0,0 +1,74 @@
+//! Checks that [`Feedback::Spawn`] reports where the spawned piece first comes to rest.
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Feedback, Game,
+    GameConfig, GameMode, Orientation, Tetromino, TwentyG,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Holds the given button (if any) until the first T-piece has spawned, returning the reported
+/// spawn and first resting positions, and the active piece right after spawning.
+fn first_spawn(twenty_g: TwentyG, held: Option<Button>) -> (ActivePiece, ActivePiece, ActivePiece) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::T),
+        initial_rotation: true,
+        twenty_g,
+        start_countdown: Duration::from_millis(100),
+        ..GameConfig::default()
+    };
+    let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+    let mut buttons_pressed = ButtonsPressed::default();
+    if let Some(button) = held {
+        buttons_pressed[button] = true;
+    }
+    let mut time = Duration::ZERO;
+    game.update(Some(buttons_pressed), time).unwrap();
+    loop {
+        time += STEP;
+        let feedback_events = game.update(None, time).unwrap();
+        let spawn = feedback_events
+            .into_iter()
+            .find_map(|(_, feedback)| match feedback {
+                Feedback::Spawn { piece, first_rest } => Some((piece, first_rest)),
+                _ => None,
+            });
+        if let Some((piece, first_rest)) = spawn {
+            let (active_piece, _) = game.state().active_piece_data.unwrap();
+            return (piece, first_rest, active_piece);
+        }
+    }
+}
+
+#[test]
+fn first_rest_is_one_row_below_spawn() {
+    let (piece, first_rest, active_piece) = first_spawn(TwentyG::Never, None);
+    assert_eq!(first_rest.orientation, piece.orientation);
+    assert_eq!(
+        first_rest.position,
+        (piece.position.0, piece.position.1 - 1)
+    );
+    assert_eq!(first_rest, active_piece);
+}
+
+#[test]
+fn first_rest_includes_initial_rotation() {
+    let (piece, first_rest, active_piece) = first_spawn(TwentyG::Never, Some(Button::RotateRight));
+    assert_eq!(piece.orientation, Orientation::N);
+    assert_eq!(first_rest.orientation, Orientation::E);
+    assert_eq!(first_rest, active_piece);
+}
+
+#[test]
+fn first_rest_is_on_the_floor_with_twenty_g() {
+    let (_, first_rest, active_piece) = first_spawn(TwentyG::Always, None);
+    assert!(first_rest.tiles().iter().any(|&((_, y), _)| y == 0));
+    assert_eq!(first_rest, active_piece);
+
+    let (_, first_rest, active_piece) = first_spawn(TwentyG::Always, Some(Button::RotateRight));
+    assert_eq!(first_rest.orientation, Orientation::E);
+    assert!(first_rest.tiles().iter().any(|&((_, y), _)| y == 0));
+    assert_eq!(first_rest, active_piece);
+}
@@ -0,0 +1,178 @@
+//! Checks spin detection on canonical spin setups.
+//!
+//! Each test prepares a board, places a piece, optionally rotates it into its slot and hard drops
+//! it, then inspects the [`Feedback::Accolade`] emitted when the piece locks.
+
+mod common;
+
+use std::time::Duration;
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Coord, Feedback,
+    Game, GameConfig, GameMode, Orientation, SpinKind, Tetromino,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// 'T-spin double' slot: a one-wide well with a three-wide opening above and an overhang on the left.
+fn tsd_board() -> Vec<Coord> {
+    let mut cells = vec![(3, 2)];
+    for x in 0..Game::WIDTH {
+        if x != 4 {
+            cells.push((x, 0));
+        }
+        if !(3..=5).contains(&x) {
+            cells.push((x, 1));
+        }
+    }
+    cells
+}
+
+/// 'T-spin mini' slot: a T-shaped opening at the left wall, only covered on the right.
+fn mini_board() -> Vec<Coord> {
+    let mut cells = Vec::new();
+    for x in 3..Game::WIDTH {
+        cells.push((x, 0));
+    }
+    for x in 2..Game::WIDTH {
+        cells.push((x, 1));
+    }
+    cells
+}
+
+fn t_piece(orientation: Orientation, position: Coord) -> ActivePiece {
+    ActivePiece {
+        shape: Tetromino::T,
+        orientation,
+        position,
+    }
+}
+
+/// Places the piece on the prepared board, presses `rotation` (if any) and hard drops, returning the
+/// locked piece's spin kind, lines cleared and score bonus.
+fn lock(cells: Vec<Coord>, piece: ActivePiece, rotation: Option<Button>) -> (SpinKind, u32, u32) {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::T),
+        ..GameConfig::default()
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        common::fill_cells(board, &cells);
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(piece).unwrap();
+    let mut press = |button: Option<Button>| {
+        let mut buttons_pressed = ButtonsPressed::default();
+        if let Some(button) = button {
+            buttons_pressed[button] = true;
+        }
+        time += STEP;
+        game.update(Some(buttons_pressed), time).unwrap()
+    };
+    if rotation.is_some() {
+        press(rotation);
+        press(None);
+    }
+    press(Some(Button::DropHard))
+        .into_iter()
+        .chain(press(None))
+        .find_map(|(_, feedback)| match feedback {
+            Feedback::Accolade {
+                spin,
+                lineclears,
+                score_bonus,
+                ..
+            } => Some((spin, lineclears, score_bonus)),
+            _ => None,
+        })
+        .expect("piece should have cleared lines")
+}
+
+#[test]
+fn tsd_rotated_in_is_full() {
+    let rotated_right = lock(
+        tsd_board(),
+        t_piece(Orientation::E, (4, 0)),
+        Some(Button::RotateRight),
+    );
+    let rotated_left = lock(
+        tsd_board(),
+        t_piece(Orientation::W, (3, 0)),
+        Some(Button::RotateLeft),
+    );
+    assert_eq!(rotated_right, (SpinKind::Full, 2, 160));
+    assert_eq!(rotated_left, (SpinKind::Full, 2, 160));
+}
+
+#[test]
+fn tsd_dropped_in_is_no_spin() {
+    let outcome = lock(tsd_board(), t_piece(Orientation::S, (3, 0)), None);
+    assert_eq!(outcome, (SpinKind::None, 2, 40));
+}
+
+#[test]
+fn wall_slot_rotated_in_is_mini() {
+    let outcome = lock(
+        mini_board(),
+        t_piece(Orientation::E, (0, 0)),
+        Some(Button::RotateLeft),
+    );
+    assert_eq!(outcome, (SpinKind::Mini, 1, 20));
+}
+
+#[test]
+fn mini_scores_above_no_spin() {
+    let (_, _, mini_bonus) = lock(
+        mini_board(),
+        t_piece(Orientation::W, (0, 0)),
+        Some(Button::RotateRight),
+    );
+    let (_, _, plain_bonus) = lock(mini_board(), t_piece(Orientation::N, (0, 0)), None);
+    assert!(plain_bonus < mini_bonus);
+}
+
+/// 'J-spin double' slot: a three-wide hole in the bottom row, covered except for its left column.
+fn j_slot_board() -> Vec<Coord> {
+    let mut cells = Vec::new();
+    for x in 0..Game::WIDTH {
+        if !(3..=5).contains(&x) {
+            cells.push((x, 0));
+        }
+        if x != 3 {
+            cells.push((x, 1));
+        }
+    }
+    cells
+}
+
+#[test]
+fn other_pieces_rotated_in_are_immobile() {
+    let j_piece = ActivePiece {
+        shape: Tetromino::J,
+        orientation: Orientation::E,
+        position: (3, 0),
+    };
+    let (spin, lineclears, _) = lock(j_slot_board(), j_piece, Some(Button::RotateLeft));
+    assert_eq!((spin, lineclears), (SpinKind::Immobile, 2));
+}
+
+#[test]
+fn wedged_without_rotation_is_no_spin() {
+    // An O-piece put into an enclosed hole cannot move up, left or right, but was not rotated.
+    let mut cells = vec![(4, 2), (5, 2)];
+    for x in 0..Game::WIDTH {
+        if !(4..=5).contains(&x) {
+            cells.push((x, 0));
+            cells.push((x, 1));
+        }
+    }
+    let o_piece = ActivePiece {
+        shape: Tetromino::O,
+        orientation: Orientation::N,
+        position: (4, 0),
+    };
+    assert_eq!(lock(cells, o_piece, None), (SpinKind::None, 2, 40));
+}
@@ -0,0 +1,61 @@
+//! Checks the helpers describing the stack of locked tiles on the board.
+
+use std::time::Duration;
+
+use tetrs_engine::{ActivePiece, Button, ButtonsPressed, Game, GameMode, Orientation, Tetromino};
+
+const STEP: Duration = Duration::from_millis(10);
+
+#[test]
+fn occupied_cells_and_stack_height() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    assert_eq!(game.occupied_cells().count(), 0);
+    assert_eq!(game.stack_height(), 0);
+    let pieces = [
+        ActivePiece {
+            shape: Tetromino::O,
+            orientation: Orientation::N,
+            position: (0, 10),
+        },
+        ActivePiece {
+            shape: Tetromino::I,
+            orientation: Orientation::E,
+            position: (5, 10),
+        },
+    ];
+    let mut time = Duration::ZERO;
+    for (pieces_played, piece) in (1..).zip(pieces) {
+        while game.state().active_piece_data.is_none() {
+            time += STEP;
+            game.update(None, time).unwrap();
+        }
+        game.set_active_piece(piece).unwrap();
+        let mut drop = ButtonsPressed::default();
+        drop[Button::DropHard] = true;
+        time += STEP;
+        game.update(Some(drop), time).unwrap();
+        while game.state().pieces_played.iter().sum::<u32>() < pieces_played {
+            time += STEP;
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+    }
+    let mut cells = game
+        .occupied_cells()
+        .map(|(coord, _)| coord)
+        .collect::<Vec<_>>();
+    cells.sort();
+    assert_eq!(
+        cells,
+        [
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (5, 0),
+            (5, 1),
+            (5, 2),
+            (5, 3)
+        ]
+    );
+    assert_eq!(game.stack_height(), 4);
+}
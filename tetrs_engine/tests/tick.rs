@@ -0,0 +1,33 @@
+//! Checks that ticking the game clock lets pieces fall and lock on their own.
+
+use std::time::Duration;
+
+use tetrs_engine::{Feedback, Game, GameMode};
+
+#[test]
+fn ticking_drops_and_locks_piece() {
+    let mut game = Game::with_seed(GameMode::zen(), 0);
+    let mut feedback_events = Vec::new();
+    // Falling 20 rows at level 1 and locking takes a little over 20 seconds.
+    for millis in (0..=25_000).step_by(100) {
+        feedback_events.extend(game.tick(Duration::from_millis(millis)));
+    }
+    let spawned_piece = feedback_events
+        .iter()
+        .find_map(|(_, feedback)| match feedback {
+            Feedback::Spawn { piece, .. } => Some(*piece),
+            _ => None,
+        })
+        .unwrap();
+    let locked_piece = feedback_events
+        .iter()
+        .find_map(|(_, feedback)| match feedback {
+            Feedback::PieceLocked(piece) => Some(*piece),
+            _ => None,
+        })
+        .expect("piece should have locked");
+    assert_eq!(locked_piece.shape, spawned_piece.shape);
+    let lowest_y = locked_piece.tiles().into_iter().map(|((_, y), _)| y).min();
+    assert_eq!(lowest_y, Some(0));
+    assert_eq!(game.state().pieces_played.iter().sum::<u32>(), 1);
+}
@@ -0,0 +1,181 @@
+//! Checks which pieces locking down across the skyline lock out under each [`TopoutRule`], how far
+//! up pieces may spawn before blocking out, and how [`TopoutPolicy::ClearTopRows`] forgives a topout.
+
+mod common;
+
+use std::{num::NonZeroU32, time::Duration};
+
+use tetrs_engine::{
+    piece_generation::TetrominoGenerator, ActivePiece, Button, ButtonsPressed, Game, GameConfig,
+    GameMode, GameOver, Orientation, SpawnRow, Tetromino, TopoutPolicy, TopoutRule,
+};
+
+const STEP: Duration = Duration::from_millis(10);
+
+/// Locks a vertical I-piece with two tiles below and two tiles above the skyline, returning how
+/// the game ended, if it did.
+fn lock_straddling_piece(topout_rule: TopoutRule) -> Option<Result<(), GameOver>> {
+    let config = GameConfig {
+        topout_rule,
+        ..GameConfig::default()
+    };
+    let skyline = config.skyline;
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        for line in &mut board[..skyline - 2] {
+            line[0] = Some(NonZeroU32::MIN);
+        }
+    });
+    let mut time = Duration::ZERO;
+    while game.state().active_piece_data.is_none() {
+        time += STEP;
+        game.update(None, time).unwrap();
+    }
+    game.set_active_piece(ActivePiece {
+        shape: Tetromino::I,
+        orientation: Orientation::E,
+        position: (0, skyline - 2),
+    })
+    .unwrap();
+    let mut drop = ButtonsPressed::default();
+    drop[Button::DropHard] = true;
+    time += STEP;
+    game.update(Some(drop), time).unwrap();
+    time += STEP;
+    // The game ending early is fine here.
+    let _ = game.update(Some(ButtonsPressed::default()), time);
+    assert_eq!(game.state().pieces_played[Tetromino::I] > 0, !game.ended());
+    game.state().end
+}
+
+#[test]
+fn straddling_piece_survives_all_above_rule() {
+    assert_eq!(lock_straddling_piece(TopoutRule::AllAboveSkyline), None);
+}
+
+#[test]
+fn straddling_piece_locks_out_under_any_above_rule() {
+    assert_eq!(
+        lock_straddling_piece(TopoutRule::AnyAboveSkyline),
+        Some(Err(GameOver::LockOut))
+    );
+}
+
+/// Fills the bottom `stack_height` rows except for the left column and spawns the first O-piece,
+/// returning the game right after.
+fn game_on_stack(stack_height: usize, config: GameConfig) -> Game {
+    let config = GameConfig {
+        tetromino_generator: TetrominoGenerator::constant(Tetromino::O),
+        ..config
+    };
+    let mut game = common::game_with_board(GameMode::zen(), config, |board| {
+        for line in &mut board[..stack_height] {
+            line[1..].fill(Some(NonZeroU32::MIN));
+        }
+    });
+    game.update(None, STEP).unwrap();
+    game
+}
+
+/// Spawns the first O-piece onto a stack as by [`game_on_stack`], returning the row it spawned at
+/// (if it did) and how the game ended (if it did).
+fn spawn_on_stack(
+    stack_height: usize,
+    spawn_row: SpawnRow,
+    spawn_retry_rows: usize,
+) -> (Option<usize>, Option<Result<(), GameOver>>) {
+    let config = GameConfig {
+        spawn_row,
+        spawn_retry_rows,
+        ..GameConfig::default()
+    };
+    let game = game_on_stack(stack_height, config);
+    let spawn_row = game
+        .state()
+        .active_piece_data
+        .map(|(piece, _)| piece.position.1);
+    (spawn_row, game.state().end)
+}
+
+#[test]
+fn blocked_spawn_blocks_out_without_retries() {
+    let GameConfig {
+        skyline,
+        spawn_row: fixed,
+        ..
+    } = GameConfig::default();
+    assert_eq!(
+        spawn_on_stack(skyline + 1, fixed, 0),
+        (None, Some(Err(GameOver::BlockOut)))
+    );
+}
+
+#[test]
+fn blocked_spawn_retries_further_up() {
+    let GameConfig {
+        skyline,
+        spawn_row: fixed,
+        ..
+    } = GameConfig::default();
+    assert_eq!(
+        spawn_on_stack(skyline + 1, fixed, 1),
+        (Some(skyline + 1), None)
+    );
+    assert_eq!(
+        spawn_on_stack(skyline + 2, fixed, 2),
+        (Some(skyline + 2), None)
+    );
+}
+
+#[test]
+fn spawn_retries_run_out() {
+    let GameConfig {
+        skyline,
+        spawn_row: fixed,
+        ..
+    } = GameConfig::default();
+    assert_eq!(
+        spawn_on_stack(skyline + 2, fixed, 1),
+        (None, Some(Err(GameOver::BlockOut)))
+    );
+}
+
+#[test]
+fn spawn_above_stack_clears_tall_stack() {
+    let GameConfig {
+        skyline,
+        spawn_row: fixed,
+        ..
+    } = GameConfig::default();
+    assert_eq!(
+        spawn_on_stack(skyline + 1, fixed, 0),
+        (None, Some(Err(GameOver::BlockOut)))
+    );
+    assert_eq!(
+        spawn_on_stack(skyline + 1, SpawnRow::AboveStack, 0),
+        (Some(skyline + 1), None)
+    );
+}
+
+#[test]
+fn clear_top_rows_policy_continues_after_topout() {
+    let skyline = GameConfig::default().skyline;
+    let config = GameConfig {
+        topout_policy: TopoutPolicy::ClearTopRows(2),
+        ..GameConfig::default()
+    };
+    let game = game_on_stack(skyline + 1, config);
+    assert_eq!(game.state().end, None);
+    assert!(game.state().active_piece_data.is_some());
+    assert_eq!(game.stack_height(), skyline - 1);
+}
+
+#[test]
+fn clear_top_rows_policy_ends_if_no_room_is_made() {
+    let height = GameConfig::default().height;
+    let config = GameConfig {
+        topout_policy: TopoutPolicy::ClearTopRows(2),
+        ..GameConfig::default()
+    };
+    let game = game_on_stack(height, config);
+    assert_eq!(game.state().end, Some(Err(GameOver::BlockOut)));
+}
@@ -40,6 +40,7 @@ impl CrosstermHandler {
         sender: &Sender<ButtonOrSignal>,
         keybinds: &HashMap<KeyCode, Button>,
         kitty_enabled: bool,
+        pause_on_focus_lost: bool,
     ) -> Self {
         let spawn = if kitty_enabled {
             Self::spawn_kitty
@@ -48,7 +49,15 @@ impl CrosstermHandler {
         };
         let flag = Arc::new(AtomicBool::new(true));
         CrosstermHandler {
-            _handle: Some((spawn(sender.clone(), flag.clone(), keybinds.clone()), flag)),
+            _handle: Some((
+                spawn(
+                    sender.clone(),
+                    flag.clone(),
+                    keybinds.clone(),
+                    pause_on_focus_lost,
+                ),
+                flag,
+            )),
         }
     }
 
@@ -67,6 +76,7 @@ impl CrosstermHandler {
         sender: Sender<ButtonOrSignal>,
         flag: Arc<AtomicBool>,
         keybinds: HashMap<KeyCode, Button>,
+        pause_on_focus_lost: bool,
     ) -> JoinHandle<()> {
         thread::spawn(move || {
             loop {
@@ -104,6 +114,11 @@ impl CrosstermHandler {
                     Ok(Event::Resize(..)) => {
                         let _ = sender.send(Err(Signal::WindowResize));
                     }
+                    // Terminal lost focus: send pause if desired.
+                    Ok(Event::FocusLost) if pause_on_focus_lost => {
+                        let _ = sender.send(Err(Signal::Pause));
+                        break;
+                    }
                     // Candidate key pressed.
                     Ok(Event::Key(KeyEvent {
                         code: key,
@@ -128,6 +143,7 @@ impl CrosstermHandler {
         sender: Sender<ButtonOrSignal>,
         flag: Arc<AtomicBool>,
         keybinds: HashMap<KeyCode, Button>,
+        pause_on_focus_lost: bool,
     ) -> JoinHandle<()> {
         thread::spawn(move || {
             loop {
@@ -166,6 +182,11 @@ impl CrosstermHandler {
                     Ok(Event::Resize(..)) => {
                         let _ = sender.send(Err(Signal::WindowResize));
                     }
+                    // Terminal lost focus: send pause if desired.
+                    Ok(Event::FocusLost) if pause_on_focus_lost => {
+                        let _ = sender.send(Err(Signal::Pause));
+                        break;
+                    }
                     // TTY simulated press repeat: ignore.
                     Ok(Event::Key(KeyEvent {
                         kind: KeyEventKind::Repeat,
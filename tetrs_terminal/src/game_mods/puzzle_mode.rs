@@ -2,7 +2,7 @@ use std::{collections::VecDeque, num::NonZeroU32};
 
 use tetrs_engine::{
     Feedback, FeedbackEvents, FnGameMod, Game, GameConfig, GameMode, GameOver, GameState,
-    InternalEvent, Limits, ModifierPoint, Tetromino,
+    InternalEvent, Limits, Line, ModifierPoint, Objective, Tetromino,
 };
 
 const MAX_STAGE_ATTEMPTS: usize = 5; // TODO: Remove.
@@ -28,7 +28,11 @@ pub fn make_game() -> Game {
                     puzzle_name.to_ascii_uppercase()
                 )
             } else {
-                format!("{} ATT. LEFT ({})", MAX_STAGE_ATTEMPTS + 1 - attempt, puzzle_name.to_ascii_uppercase())
+                format!(
+                    "{} ATT. LEFT ({})",
+                    MAX_STAGE_ATTEMPTS + 1 - attempt,
+                    puzzle_name.to_ascii_uppercase()
+                )
             }),
         ));
         // Queue pieces and lines.
@@ -38,15 +42,17 @@ pub fn make_game() -> Game {
             .iter()
             .rev()
             .map(|line| {
-                line.map(|b| {
+                Line::from(line.map(|b| {
                     if b == b' ' {
                         None
                     } else {
-                        Some(unsafe { NonZeroU32::new_unchecked(254) })
+                        Some(Game::GARBAGE_TILE)
                     }
-                })
+                }))
             })
-            .chain(std::iter::repeat(Default::default()))
+            .chain(std::iter::repeat(
+                vec![None; Game::WIDTH].into_boxed_slice(),
+            ))
             .zip(state.board.iter_mut())
         {
             *board_line = puzzle_line;
@@ -143,6 +149,8 @@ pub fn make_game() -> Game {
             )),
             ..Default::default()
         },
+        objective: Some(Objective::Level),
+        recommended_config: None,
     });
     game.config_mut().preview_count = 0;
     unsafe { game.add_modifier(puzzle_modifier) };
@@ -150,6 +158,7 @@ pub fn make_game() -> Game {
 }
 
 #[rustfmt::skip]
+#[allow(clippy::type_complexity)]
 fn list_of_puzzles() -> [(&'static str, Vec<&'static [u8; 10]>, VecDeque<Tetromino>); 24] {
     [
         /* Puzzle template.
@@ -12,15 +12,14 @@ use crossterm::{
     terminal, QueueableCommand,
 };
 use tetrs_engine::{
-    Button, Coord, Feedback, FeedbackEvents, Game, GameState, GameTime, Orientation, Tetromino,
-    TileTypeID,
+    ActivePiece, Board, Button, Coord, Feedback, FeedbackEvents, Game, GameState, GameTime,
+    Objective, Orientation, SpinKind, Tetromino, TileTypeID,
 };
 
 use crate::{
     game_renderers::GameScreenRenderer,
     terminal_tetrs::{
-        format_duration, format_key, format_keybinds, App, GraphicsColor, GraphicsStyle,
-        RunningGameStats,
+        format_duration, format_key, format_keybinds, App, GraphicsColor, GraphicsStyle, Palette,
     },
 };
 
@@ -205,6 +204,8 @@ pub struct Renderer {
     visual_events: Vec<(GameTime, Feedback, bool)>,
     messages: Vec<(GameTime, String)>,
     hard_drop_tiles: Vec<(GameTime, Coord, usize, TileTypeID, bool)>,
+    /// The last suggested placement, along with the piece type and board it was computed for.
+    placement_hint: Option<(Tetromino, Board, Option<ActivePiece>)>,
 }
 
 impl GameScreenRenderer for Renderer {
@@ -213,7 +214,6 @@ impl GameScreenRenderer for Renderer {
         &mut self,
         app: &mut App<T>,
         game: &mut Game,
-        action_stats: &mut RunningGameStats,
         new_feedback_events: FeedbackEvents,
         screen_resized: bool,
     ) -> io::Result<()>
@@ -239,6 +239,16 @@ impl GameScreenRenderer for Renderer {
             score,
             consecutive_line_clears: _,
             back_to_back_special_clears: _,
+            perfect_clears: _,
+            time_bonus,
+            input_counts: _,
+            spawns_since_seen: _,
+            max_stack_height: _,
+            queued_initial_rotation: _,
+            queued_garbage: _,
+            piece_inputs: _,
+            finesse_faults: _,
+            das_charged: _,
         } = game.state();
         // Screen: some titles.
         let mode_name = game.mode().name.to_ascii_uppercase();
@@ -247,7 +257,7 @@ impl GameScreenRenderer for Renderer {
             game.mode().limits.time.map(|(_, max_dur)| {
                 (
                     "Time left:",
-                    format_duration(max_dur.saturating_sub(*game_time)),
+                    format_duration((max_dur + *time_bonus).saturating_sub(*game_time)),
                 )
             }),
             game.mode().limits.pieces.map(|(_, max_pcs)| {
@@ -284,7 +294,7 @@ impl GameScreenRenderer for Renderer {
             "Marathon" => ("Score:", score.to_string()),
             "40-Lines" => ("Time taken:", format_duration(*game_time)),
             "Time Trial" => ("Lines cleared:", lines_cleared.to_string()),
-            "Master" => ("Lines cleared:", lines_cleared.to_string()),
+            "Master" | "Death" => ("Lines cleared:", lines_cleared.to_string()),
             "Puzzle" => ("", "".to_string()),
             _ => ("Lines cleared:", lines_cleared.to_string()),
         };
@@ -419,14 +429,16 @@ impl GameScreenRenderer for Renderer {
         let (x_preview_small, y_preview_small) = (48, 14);
         let (x_preview_minuscule, y_preview_minuscule) = (48, 15);
         let (x_messages, y_messages) = (47, 17);
-        let pos_board = |(x, y)| (x_board + 2 * x, y_board + Game::SKYLINE - y);
+        // NOTE: The screen layout is made for boards of the default size.
+        let skyline = game.config().skyline;
+        let pos_board = |(x, y)| (x_board + 2 * x, y_board + skyline - y);
         // Board: helpers.
         #[rustfmt::skip]
-        let tile_color = match app.settings().graphics_color {
-            GraphicsColor::Monochrome => {
+        let tile_color = match (app.settings().graphics_color, app.settings().palette) {
+            (GraphicsColor::Monochrome, _) => {
                 |_tile: TileTypeID| None
             },
-            GraphicsColor::Color16 => {
+            (GraphicsColor::Color16, _) => {
                 |tile: TileTypeID| {
                     Some(match tile.get() {
                         1 => Color::Yellow,
@@ -442,7 +454,25 @@ impl GameScreenRenderer for Renderer {
                     })
                 }
             },
-            GraphicsColor::ColorRGB => {
+            // NOTE: Colors from the Okabe-Ito palette, which stay distinguishable with color vision deficiencies.
+            (GraphicsColor::ColorRGB, Palette::Colorblind) => {
+                |tile: TileTypeID| {
+                    Some(match Tetromino::from_tiletypeid(tile) {
+                        Some(Tetromino::O) => Color::Rgb { r:240, g:228, b: 66 },
+                        Some(Tetromino::I) => Color::Rgb { r: 86, g:180, b:233 },
+                        Some(Tetromino::S) => Color::Rgb { r:  0, g:158, b:115 },
+                        Some(Tetromino::Z) => Color::Rgb { r:213, g: 94, b:  0 },
+                        Some(Tetromino::T) => Color::Rgb { r:204, g:121, b:167 },
+                        Some(Tetromino::L) => Color::Rgb { r:230, g:159, b:  0 },
+                        Some(Tetromino::J) => Color::Rgb { r:  0, g:114, b:178 },
+                        None if tile == Game::GARBAGE_TILE => Color::Rgb { r:127, g:127, b:127 },
+                        None if tile == Game::WALL_TILE => Color::Rgb { r:  0, g:  0, b: 0 },
+                        // NOTE: Tile ids unknown to the palette (e.g. from mods) are drawn in a neutral white.
+                        None => Color::Rgb { r:255, g:255, b:255 },
+                    })
+                }
+            },
+            (GraphicsColor::ColorRGB, Palette::Standard) => {
                 |tile: TileTypeID| {
                     Some(match tile.get() {
                         1 => Color::Rgb { r:254, g:203, b:  0 },
@@ -483,13 +513,13 @@ impl GameScreenRenderer for Renderer {
         }
         self.hard_drop_tiles.retain(|elt| elt.4);
         // Board: draw fixed tiles.
-        let (tile_ground, tile_ghost, tile_active, tile_preview) =
+        let (tile_ground, tile_ghost, tile_active, tile_preview, tile_hint) =
             match app.settings().graphics_style {
-                GraphicsStyle::Electronika60 => ("▮▮", " .", "▮▮", "▮▮"),
-                GraphicsStyle::ASCII => ("##", "::", "[]", "[]"),
-                GraphicsStyle::Unicode => ("██", "░░", "▓▓", "▒▒"),
+                GraphicsStyle::Electronika60 => ("▮▮", " .", "▮▮", "▮▮", " ,"),
+                GraphicsStyle::ASCII => ("##", "::", "[]", "[]", ".."),
+                GraphicsStyle::Unicode => ("██", "░░", "▓▓", "▒▒", "··"),
             };
-        for (y, line) in board.iter().enumerate().take(21).rev() {
+        for (y, line) in board.iter().enumerate().take(skyline + 1).rev() {
             for (x, cell) in line.iter().enumerate() {
                 if let Some(tile_type_id) = cell {
                     self.screen.buffer_str(
@@ -502,19 +532,60 @@ impl GameScreenRenderer for Renderer {
         }
         // If a piece is in play.
         if let Some((active_piece, _)) = active_piece_data {
+            // Draw suggested placement.
+            if app.settings().beginner_assist {
+                // Only recompute the suggestion when a different piece is in play or the board
+                // changed, not every frame.
+                let hint_outdated = !matches!(
+                    &self.placement_hint,
+                    Some((shape, hint_board, _)) if *shape == active_piece.shape && hint_board == board
+                );
+                if hint_outdated {
+                    self.placement_hint =
+                        Some((active_piece.shape, board.clone(), game.suggest_placement()));
+                }
+                if let Some((_, _, Some(hint_piece))) = &self.placement_hint {
+                    for (tile_pos, tile_type_id) in hint_piece.tiles() {
+                        if tile_pos.1 <= skyline {
+                            self.screen.buffer_str(
+                                tile_hint,
+                                tile_color(tile_type_id),
+                                pos_board(tile_pos),
+                            );
+                        }
+                    }
+                }
+            }
             // Draw ghost piece.
-            for (tile_pos, tile_type_id) in active_piece.well_piece(board).tiles() {
-                if tile_pos.1 <= Game::SKYLINE {
-                    self.screen.buffer_str(
-                        tile_ghost,
-                        tile_color(tile_type_id),
-                        pos_board(tile_pos),
-                    );
+            if game.config().show_ghost {
+                for (tile_pos, tile_type_id) in active_piece.ghost_tiles(board) {
+                    if tile_pos.1 <= skyline {
+                        self.screen.buffer_str(
+                            tile_ghost,
+                            tile_color(tile_type_id),
+                            pos_board(tile_pos),
+                        );
+                    }
                 }
             }
-            // Draw active piece.
+            // Draw active piece, shrinking it in steps as its lock delay runs out.
+            // NOTE: Only glyphs change (not colors) so this also shows in monochrome.
+            let lock_glyphs: &[&str] = match app.settings().graphics_style {
+                GraphicsStyle::Electronika60 => &[tile_active],
+                GraphicsStyle::ASCII => &[tile_active, "()", "<>", "--"],
+                GraphicsStyle::Unicode => &[tile_active, "▆▆", "▄▄", "▂▂"],
+            };
+            let tile_active = match game.lock_meter() {
+                Some(lock_meter) => {
+                    let n = lock_glyphs.len();
+                    // NOTE: Truncation intended; the meter is quantized so the glyph is stable across frames.
+                    let step = (((1.0 - lock_meter) * n as f64) as usize).min(n - 1);
+                    lock_glyphs[step]
+                }
+                None => tile_active,
+            };
             for (tile_pos, tile_type_id) in active_piece.tiles() {
-                if tile_pos.1 <= Game::SKYLINE {
+                if tile_pos.1 <= skyline {
                     self.screen.buffer_str(
                         tile_active,
                         tile_color(tile_type_id),
@@ -624,7 +695,7 @@ impl GameScreenRenderer for Renderer {
                         continue;
                     };
                     for (tile_pos, _tile_type_id) in piece.tiles() {
-                        if tile_pos.1 <= Game::SKYLINE {
+                        if tile_pos.1 <= skyline {
                             self.screen
                                 .buffer_str(tile, color_locking, pos_board(tile_pos));
                         }
@@ -686,14 +757,14 @@ impl GameScreenRenderer for Renderer {
                         continue;
                     };
                     for y_line in lines_cleared {
-                        let pos = (x_board, y_board + Game::SKYLINE - *y_line);
+                        let pos = (x_board, y_board + skyline - *y_line);
                         self.screen
                             .buffer_str(animation_lineclear[idx], color_lineclear, pos);
                     }
                 }
-                Feedback::HardDrop(_top_piece, bottom_piece) => {
+                Feedback::QuickDrop(_top_piece, bottom_piece) => {
                     for ((x_tile, y_tile), tile_type_id) in bottom_piece.tiles() {
-                        for y in y_tile..Game::SKYLINE {
+                        for y in y_tile..skyline {
                             self.hard_drop_tiles.push((
                                 *event_time,
                                 (x_tile, y),
@@ -714,15 +785,19 @@ impl GameScreenRenderer for Renderer {
                     combo,
                     back_to_back,
                 } => {
-                    action_stats.1.push(*score_bonus);
                     let mut strs = Vec::new();
                     strs.push(format!("+{score_bonus}"));
                     if *perfect_clear {
                         strs.push("Perfect".to_string());
                     }
-                    if *spin {
-                        strs.push(format!("{shape:?}-Spin"));
-                        action_stats.0[0] += 1;
+                    match spin {
+                        SpinKind::None => {}
+                        SpinKind::Mini => {
+                            strs.push(format!("{shape:?}-Spin Mini"));
+                        }
+                        SpinKind::Full | SpinKind::Immobile => {
+                            strs.push(format!("{shape:?}-Spin"));
+                        }
                     }
                     let clear_action = match lineclears {
                         1 => "Single",
@@ -749,11 +824,6 @@ impl GameScreenRenderer for Renderer {
                         _ => "unreachable",
                     }
                     .to_string();
-                    if *lineclears <= 4 {
-                        action_stats.0[usize::try_from(*lineclears).unwrap()] += 1;
-                    } else {
-                        // TODO: Record higher lineclears, if even possible.
-                    }
                     strs.push(clear_action);
                     if *combo > 1 {
                         strs.push(format!("({combo}.combo)"));
@@ -764,6 +834,41 @@ impl GameScreenRenderer for Renderer {
                     self.messages.push((*event_time, strs.join(" ")));
                     *relevant = false;
                 }
+                Feedback::LevelUp(level) => {
+                    self.messages.push((*event_time, format!("Level {level}!")));
+                    *relevant = false;
+                }
+                Feedback::TimeBonus(time_bonus) => {
+                    self.messages
+                        .push((*event_time, format!("+{}s", time_bonus.as_secs())));
+                    *relevant = false;
+                }
+                Feedback::Countdown(remaining) => {
+                    self.messages.push((
+                        *event_time,
+                        format!("Ready... {}", remaining.as_secs_f64().ceil()),
+                    ));
+                    *relevant = false;
+                }
+                Feedback::FinesseFault {
+                    inputs, optimal, ..
+                } => {
+                    // Only warn about finesse where it is what the player is scored on.
+                    if game.mode().objective == Some(Objective::FinesseFaults) {
+                        self.messages.push((
+                            *event_time,
+                            format!("Finesse fault ({inputs}/{optimal} inputs)"),
+                        ));
+                    }
+                    *relevant = false;
+                }
+                Feedback::Spawn { .. }
+                | Feedback::LineShifts(..)
+                | Feedback::BoardChanged { .. }
+                | Feedback::PerfectClear { .. }
+                | Feedback::GarbageInserted(_) => {
+                    *relevant = false;
+                }
                 Feedback::Message(msg) => {
                     self.messages.push((*event_time, msg.clone()));
                     *relevant = false;
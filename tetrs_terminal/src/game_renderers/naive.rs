@@ -8,13 +8,11 @@ use crossterm::{
     style::{self, Print},
     terminal, QueueableCommand,
 };
-use tetrs_engine::{Feedback, FeedbackEvents, Game, GameState, GameTime};
+use tetrs_engine::{Feedback, FeedbackEvents, Game, GameTime, SpinKind, Tetromino};
 
-use crate::{
-    game_renderers::GameScreenRenderer,
-    terminal_tetrs::{App, RunningGameStats},
-};
+use crate::{game_renderers::GameScreenRenderer, terminal_tetrs::App};
 
+#[allow(dead_code)]
 #[derive(Clone, Default, Debug)]
 pub struct Renderer {
     feedback_event_buffer: VecDeque<(GameTime, Feedback)>,
@@ -25,7 +23,6 @@ impl GameScreenRenderer for Renderer {
         &mut self,
         app: &mut App<T>,
         game: &mut Game,
-        _action_stats: &mut RunningGameStats,
         new_feedback_events: FeedbackEvents,
         _screen_resized: bool,
     ) -> io::Result<()>
@@ -33,18 +30,8 @@ impl GameScreenRenderer for Renderer {
         T: Write,
     {
         // Draw game stuf
-        let GameState {
-            time: game_time,
-            board,
-            active_piece_data,
-            ..
-        } = game.state();
-        let mut temp_board = board.clone();
-        if let Some((active_piece, _)) = active_piece_data {
-            for ((x, y), tile_type_id) in active_piece.tiles() {
-                temp_board[y][x] = Some(tile_type_id);
-            }
-        }
+        let game_time = game.state().time;
+        let temp_board = game.render_board();
         app.term
             .queue(cursor::MoveTo(0, 0))?
             .queue(terminal::Clear(terminal::ClearType::FromCursorDown))?;
@@ -56,16 +43,14 @@ impl GameScreenRenderer for Renderer {
                 "{idx:02} |{}|",
                 line.iter()
                     .map(|cell| {
-                        cell.map_or(" .", |tile| match tile.get() {
-                            1 => "OO",
-                            2 => "II",
-                            3 => "SS",
-                            4 => "ZZ",
-                            5 => "TT",
-                            6 => "LL",
-                            7 => "JJ",
-                            255 => "WW",
-                            t => unimplemented!("formatting unknown tile id {t}"),
+                        cell.map_or(" .".to_string(), |tile| {
+                            let c = match Tetromino::from_tiletypeid(tile) {
+                                Some(tetromino) => tetromino.name_char(),
+                                None if tile == Game::GARBAGE_TILE => 'X',
+                                None if tile == Game::WALL_TILE => 'W',
+                                None => unimplemented!("formatting unknown tile id {tile}"),
+                            };
+                            format!("{c}{c}")
                         })
                     })
                     .collect::<Vec<_>>()
@@ -96,8 +81,10 @@ impl GameScreenRenderer for Renderer {
                     back_to_back,
                 } => {
                     let mut strs = Vec::new();
-                    if *spin {
-                        strs.push(format!("{shape:?}-Spin"));
+                    match spin {
+                        SpinKind::None => {}
+                        SpinKind::Mini => strs.push(format!("{shape:?}-Spin Mini")),
+                        SpinKind::Full | SpinKind::Immobile => strs.push(format!("{shape:?}-Spin")),
                     }
                     let clear_action = match lineclears {
                         1 => "Single",
@@ -120,9 +107,20 @@ impl GameScreenRenderer for Renderer {
                     strs.push(format!("+{score_bonus}"));
                     strs.join(" ")
                 }
+                Feedback::Spawn { .. } => continue,
+                Feedback::Countdown(remaining) => {
+                    format!("{}...", remaining.as_secs_f64().ceil())
+                }
+                Feedback::LevelUp(level) => format!("Level {level}!"),
+                Feedback::TimeBonus(time_bonus) => format!("+{}s", time_bonus.as_secs()),
                 Feedback::PieceLocked(_) => continue,
                 Feedback::LineClears(..) => continue,
-                Feedback::HardDrop(_, _) => continue,
+                Feedback::LineShifts(..) => continue,
+                Feedback::BoardChanged { .. } => continue,
+                Feedback::QuickDrop(_, _) => continue,
+                Feedback::PerfectClear { .. } => continue,
+                Feedback::GarbageInserted(_) => continue,
+                Feedback::FinesseFault { .. } => continue,
                 Feedback::Message(s) => s.clone(),
             });
         }
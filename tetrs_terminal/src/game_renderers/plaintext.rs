@@ -0,0 +1,31 @@
+use tetrs_engine::{Game, Tetromino};
+
+/// Assembles a plain ASCII snapshot of the visible board, including the active piece.
+///
+/// The output contains no escape codes, making it suitable for writing to a file.
+pub fn board_snapshot(game: &Game) -> String {
+    let game_time = game.state().time;
+    let temp_board = game.render_board();
+    let frame = format!("   +{}+\n", "-".repeat(2 * game.config().width));
+    let mut snapshot = frame.clone();
+    for (idx, line) in temp_board
+        .iter()
+        .take(game.config().skyline)
+        .enumerate()
+        .rev()
+    {
+        let txt_line = line
+            .iter()
+            .map(|cell| {
+                cell.map_or(" .".to_string(), |tile| {
+                    let c = Tetromino::from_tiletypeid(tile).map_or('#', |t| t.name_char());
+                    format!("{c}{c}")
+                })
+            })
+            .collect::<String>();
+        snapshot.push_str(&format!("{idx:02} |{txt_line}|\n"));
+    }
+    snapshot.push_str(&frame);
+    snapshot.push_str(&format!("   {:?}\n", game_time));
+    snapshot
+}
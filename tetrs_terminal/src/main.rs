@@ -3,7 +3,10 @@ mod game_mods;
 mod game_renderers;
 pub mod terminal_tetrs;
 
-use std::io::{self, Write};
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+};
 
 use clap::Parser;
 
@@ -13,12 +16,23 @@ struct Args {
     /// The framerate at which to run the main game.
     #[arg(short, long)]
     fps: Option<u32>,
+    /// Export all past games as CSV to the given path and exit.
+    #[arg(long, value_name = "PATH")]
+    export: Option<PathBuf>,
+    /// Append a plain-text snapshot of the board to the given file whenever a piece locks.
+    #[arg(long, value_name = "PATH")]
+    board_log: Option<PathBuf>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
+    if let Some(path) = args.export {
+        terminal_tetrs::App::<io::Stdout>::export_savefile_csv(&path)?;
+        println!("exported past games to {}", path.display());
+        return Ok(());
+    }
     let stdout = io::BufWriter::new(io::stdout());
-    let mut app = terminal_tetrs::App::new(stdout, args.fps);
+    let mut app = terminal_tetrs::App::new(stdout, args.fps, args.board_log);
     std::panic::set_hook(Box::new(|panic_info| {
         if let Ok(mut file) = std::fs::File::create("tetrs_terminal_error_message.txt") {
             let _ = file.write(panic_info.to_string().as_bytes());
@@ -1,11 +1,13 @@
 use std::{
+    cell::RefCell,
     collections::HashMap,
     env,
     fmt::Debug,
     fs::File,
     io::{self, Read, Write},
     num::NonZeroU32,
-    path::PathBuf,
+    path::{Path, PathBuf},
+    rc::Rc,
     sync::mpsc,
     time::{Duration, Instant},
 };
@@ -14,7 +16,7 @@ use crossterm::{
     cursor::{self, MoveTo},
     event::{
         self, Event, KeyCode, KeyEvent,
-        KeyEventKind::{Press, Repeat},
+        KeyEventKind::{Press, Release, Repeat},
         KeyModifiers,
     },
     style::{self, Print, PrintStyledContent, Stylize},
@@ -23,10 +25,10 @@ use crossterm::{
 };
 use tetrs_engine::{
     piece_generation::TetrominoGenerator, piece_rotation::RotationSystem, Button, ButtonsPressed,
-    Game, GameConfig, GameMode, GameState, Limits,
+    Feedback, FnGameObserver, Game, GameConfig, GameMode, GameState, Limits, SpinKind,
 };
 
-use crate::game_renderers::{cached::Renderer, GameScreenRenderer};
+use crate::game_renderers::{cached::Renderer, plaintext, GameScreenRenderer};
 use crate::{
     game_input_handler::{ButtonOrSignal, CrosstermHandler, Signal},
     game_mods,
@@ -35,6 +37,34 @@ use crate::{
 // NOTE: This could be more general and less ad-hoc. Count number of I-Spins, J-Spins, etc..
 pub type RunningGameStats = ([u32; 5], Vec<u32>);
 
+/// Game observer which records the actions and score bonuses of each line clear.
+fn running_game_stats_observer(
+    running_game_stats: Rc<RefCell<RunningGameStats>>,
+) -> FnGameObserver {
+    Box::new(move |_config, _mode, _state, feedback_events, _point| {
+        let mut running_game_stats = running_game_stats.borrow_mut();
+        for (_, feedback) in feedback_events {
+            if let Feedback::Accolade {
+                score_bonus,
+                spin,
+                lineclears,
+                ..
+            } = feedback
+            {
+                running_game_stats.1.push(*score_bonus);
+                if *spin != SpinKind::None {
+                    running_game_stats.0[0] += 1;
+                }
+                if *lineclears <= 4 {
+                    running_game_stats.0[usize::try_from(*lineclears).unwrap()] += 1;
+                } else {
+                    // TODO: Record higher lineclears, if even possible.
+                }
+            }
+        }
+    })
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct FinishedGameStats {
     timestamp: String,
@@ -48,6 +78,63 @@ impl FinishedGameStats {
     fn was_successful(&self) -> bool {
         self.last_state.end.is_some_and(|fin| fin.is_ok())
     }
+
+    /// Orders two past games as they are listed on the scoreboard, best first.
+    fn scoreboard_cmp(stats1: &Self, stats2: &Self) -> std::cmp::Ordering {
+        // First sort by gamemode.
+        stats1.gamemode.name.cmp(&stats2.gamemode.name).then_with(|| {
+            // Sort by whether game was finished successfully or not.
+            let end1 = stats1.last_state.end.is_some_and(|end| end.is_ok());
+            let end2 = stats2.last_state.end.is_some_and(|end| end.is_ok());
+            end1.cmp(&end2).reverse().then_with(|| {
+                // Depending on gamemode, sort differently.
+                match stats1.gamemode.name.as_str() {
+                    "Marathon" => {
+                        // Sort desc by level.
+                        stats1.last_state.level.cmp(&stats2.last_state.level).reverse().then_with(||
+                            // Sort desc by score.
+                            stats1.last_state.score.cmp(&stats2.last_state.score).reverse()
+                        )
+                    },
+                    "40-Lines" => {
+                        // Sort desc by lines.
+                        stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse().then_with(||
+                            // Sort asc by time.
+                            stats1.last_state.time.cmp(&stats2.last_state.time)
+                        )
+                    },
+                    "Time Trial" => {
+                        // Sort asc by time.
+                        stats1.last_state.time.cmp(&stats2.last_state.time).then_with(||
+                            // Sort by desc lines.
+                            stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse()
+                        )
+                    },
+                    "Master" | "Death" => {
+                        // Sort desc by lines.
+                        stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse()
+                    },
+                    "Puzzle" => {
+                        // Sort desc by level.
+                        stats1.last_state.level.cmp(&stats2.last_state.level).reverse().then_with(||
+                            // Sort asc by time.
+                            stats1.last_state.time.cmp(&stats2.last_state.time)
+                        )
+                    },
+                    // Other modes sort by their objective, if any.
+                    _ => match stats1.gamemode.objective {
+                        Some(objective) => objective.compare(&stats1.last_state, &stats2.last_state),
+                        // Sort desc by lines.
+                        None => stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse(),
+                    },
+                }
+                .then_with(|| {
+                    // Sort asc by timestamp.
+                    stats1.timestamp.cmp(&stats2.timestamp)
+                })
+            })
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -57,9 +144,7 @@ enum Menu {
     Game {
         game: Box<Game>,
         time_started: Instant,
-        last_paused: Instant,
-        total_duration_paused: Duration,
-        running_game_stats: RunningGameStats,
+        running_game_stats: Rc<RefCell<RunningGameStats>>,
         game_renderer: Box<Renderer>,
     },
     GameOver(Box<FinishedGameStats>),
@@ -117,8 +202,72 @@ pub enum GraphicsColor {
     ColorRGB,
 }
 
+#[derive(
+    Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum Palette {
+    Standard,
+    Colorblind,
+}
+
+#[derive(
+    Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum Theme {
+    Classic,
+    Modern,
+    Mono,
+    Colorblind,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 4] = [
+        Theme::Classic,
+        Theme::Modern,
+        Theme::Mono,
+        Theme::Colorblind,
+    ];
+
+    /// Sets all visual settings (and whether to show the ghost piece) according to the theme.
+    pub fn apply(self, settings: &mut Settings, game_config: &mut GameConfig) {
+        let (graphics_style, graphics_color, palette, show_ghost) = match self {
+            Theme::Classic => (
+                GraphicsStyle::Electronika60,
+                GraphicsColor::Monochrome,
+                Palette::Standard,
+                false,
+            ),
+            Theme::Modern => (
+                GraphicsStyle::Unicode,
+                GraphicsColor::ColorRGB,
+                Palette::Standard,
+                true,
+            ),
+            Theme::Mono => (
+                GraphicsStyle::Unicode,
+                GraphicsColor::Monochrome,
+                Palette::Standard,
+                true,
+            ),
+            Theme::Colorblind => (
+                GraphicsStyle::Unicode,
+                GraphicsColor::ColorRGB,
+                Palette::Colorblind,
+                true,
+            ),
+        };
+        settings.theme = Some(self);
+        settings.graphics_style = graphics_style;
+        settings.graphics_color = graphics_color;
+        settings.palette = palette;
+        game_config.show_ghost = show_ghost;
+    }
+}
+
+// Settings missing from a savefile (e.g. one of an earlier version) take their default value.
 #[serde_with::serde_as]
 #[derive(PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct Settings {
     #[serde_as(as = "HashMap<serde_with::json::JsonString, _>")]
     pub keybinds: HashMap<KeyCode, Button>,
@@ -126,7 +275,33 @@ pub struct Settings {
     pub show_fps: bool,
     pub graphics_style: GraphicsStyle,
     pub graphics_color: GraphicsColor,
+    pub palette: Palette,
+    // `None` if the visual settings were changed individually.
+    pub theme: Option<Theme>,
     pub save_data_on_exit: bool,
+    pub menu_repeat_delay: Duration,
+    pub menu_repeat_rate: Duration,
+    pub beginner_assist: bool,
+    pub pause_on_focus_lost: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            keybinds: CrosstermHandler::default_keybinds(),
+            game_fps: 30.0,
+            show_fps: false,
+            graphics_style: GraphicsStyle::Unicode,
+            graphics_color: GraphicsColor::ColorRGB,
+            palette: Palette::Standard,
+            theme: Some(Theme::Modern),
+            save_data_on_exit: false,
+            menu_repeat_delay: Duration::from_millis(200),
+            menu_repeat_rate: Duration::from_millis(50),
+            beginner_assist: false,
+            pause_on_focus_lost: true,
+        }
+    }
 }
 
 // For the "New Game" menu.
@@ -151,14 +326,27 @@ pub struct CustomModeStore {
     mode_limit: Option<Stat>,
 }
 
+// The contents of the savefile.
+type SaveData = (
+    Settings,
+    CustomModeStore,
+    GameConfig,
+    Vec<FinishedGameStats>,
+);
+
 #[derive(Clone, Debug)]
 pub struct App<T: Write> {
     pub term: T,
     kitty_enabled: bool,
+    menu_key_held: Option<(KeyCode, Instant)>,
     settings: Settings,
     custom_mode: CustomModeStore,
     game_config: GameConfig,
     past_games: Vec<FinishedGameStats>,
+    // Whether a savefile exists but could not be loaded, in which case it must not be lost.
+    savefile_unreadable: bool,
+    // Where to append a plain-text snapshot of the board whenever a piece locks, for debugging.
+    board_log: Option<PathBuf>,
 }
 
 impl<T: Write> Drop for App<T> {
@@ -167,16 +355,22 @@ impl<T: Write> Drop for App<T> {
         let savefile_path = Self::savefile_path();
         // If the user wants their data stored, try to do so.
         if self.settings.save_data_on_exit {
+            // Keep a savefile we could not read around instead of overwriting it.
+            if self.savefile_unreadable {
+                let _ = std::fs::rename(&savefile_path, savefile_path.with_extension("json.bak"));
+            }
             if let Err(_e) = self.store_local(savefile_path) {
                 // TODO: Make this debuggable.
                 //eprintln!("Could not save settings this time: {e} ");
                 //std::thread::sleep(Duration::from_secs(4));
             }
-        // Otherwise check if savefile exists.
-        } else if let Ok(exists) = savefile_path.try_exists() {
-            // Delete it for them if it does.
-            if exists {
-                let _ = std::fs::remove_file(savefile_path);
+        // Otherwise check if savefile exists (never discarding one we could not read).
+        } else if !self.savefile_unreadable {
+            if let Ok(exists) = savefile_path.try_exists() {
+                // Delete it for them if it does.
+                if exists {
+                    let _ = std::fs::remove_file(savefile_path);
+                }
             }
         }
         // Console epilogue: de-initialization.
@@ -184,6 +378,7 @@ impl<T: Write> Drop for App<T> {
             let _ = self.term.execute(event::PopKeyboardEnhancementFlags);
         }
         let _ = terminal::disable_raw_mode();
+        let _ = self.term.execute(event::DisableFocusChange);
         let _ = self.term.execute(style::ResetColor);
         let _ = self.term.execute(cursor::Show);
         let _ = self.term.execute(terminal::LeaveAlternateScreen);
@@ -195,13 +390,15 @@ impl<T: Write> App<T> {
     pub const H_MAIN: u16 = 24;
 
     pub const SAVEFILE_NAME: &'static str = ".tetrs_terminal.json";
+    pub const EXPORT_NAME: &'static str = "tetrs_terminal_scores.csv";
 
-    pub fn new(mut terminal: T, fps: Option<u32>) -> Self {
+    pub fn new(mut terminal: T, fps: Option<u32>, board_log: Option<PathBuf>) -> Self {
         // Console prologue: Initializion.
         // TODO: Handle errors?
         let _ = terminal.execute(terminal::EnterAlternateScreen);
         let _ = terminal.execute(terminal::SetTitle("Tetrs Terminal"));
         let _ = terminal.execute(cursor::Hide);
+        let _ = terminal.execute(event::EnableFocusChange);
         let _ = terminal::enable_raw_mode();
         let kitty_enabled = terminal::supports_keyboard_enhancement().unwrap_or(false);
         if kitty_enabled {
@@ -212,14 +409,7 @@ impl<T: Write> App<T> {
         }
         let mut app = Self {
             term: terminal,
-            settings: Settings {
-                keybinds: CrosstermHandler::default_keybinds(),
-                game_fps: 30.0,
-                show_fps: false,
-                graphics_style: GraphicsStyle::Unicode,
-                graphics_color: GraphicsColor::ColorRGB,
-                save_data_on_exit: false,
-            },
+            settings: Settings::default(),
             custom_mode: CustomModeStore {
                 name: "Custom Mode".to_string(),
                 start_level: NonZeroU32::MIN,
@@ -229,8 +419,12 @@ impl<T: Write> App<T> {
             game_config: GameConfig::default(),
             past_games: vec![],
             kitty_enabled,
+            menu_key_held: None,
+            savefile_unreadable: false,
+            board_log,
         };
-        if let Err(_e) = app.load_local() {
+        if let Err(e) = app.load_local() {
+            app.savefile_unreadable = e.kind() != io::ErrorKind::NotFound;
             // TODO: Make this debuggable.
             //eprintln!("Could not loading settings: {e}");
             //std::thread::sleep(Duration::from_secs(5));
@@ -273,6 +467,23 @@ impl<T: Write> App<T> {
         .join(Self::SAVEFILE_NAME)
     }
 
+    /// Writes all past games as CSV, c.f. [`write_csv`].
+    pub fn export_csv(&self, path: &Path) -> io::Result<()> {
+        write_csv(&self.past_games, path)
+    }
+
+    /// Writes all past games stored in the savefile as CSV, without setting up the terminal or
+    /// touching the savefile.
+    pub fn export_savefile_csv(path: &Path) -> io::Result<()> {
+        let past_games = match Self::read_savefile() {
+            Ok((_, _, _, past_games)) => past_games,
+            // Without a savefile there are simply no past games.
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        write_csv(&past_games, path)
+    }
+
     fn store_local(&mut self, path: PathBuf) -> io::Result<()> {
         self.past_games = self
             .past_games
@@ -296,16 +507,20 @@ impl<T: Write> App<T> {
         Ok(())
     }
 
-    fn load_local(&mut self) -> io::Result<()> {
+    fn read_savefile() -> io::Result<SaveData> {
         let mut file = File::open(Self::savefile_path())?;
         let mut save_str = String::new();
         file.read_to_string(&mut save_str)?;
+        Ok(serde_json::from_str::<SaveData>(&save_str)?)
+    }
+
+    fn load_local(&mut self) -> io::Result<()> {
         (
             self.settings,
             self.custom_mode,
             self.game_config,
             self.past_games,
-        ) = serde_json::from_str(&save_str)?;
+        ) = Self::read_savefile()?;
         Ok(())
     }
 
@@ -328,15 +543,11 @@ impl<T: Write> App<T> {
                 Menu::Game {
                     game,
                     time_started,
-                    total_duration_paused,
-                    last_paused,
                     running_game_stats,
                     game_renderer,
                 } => self.game(
                     game,
                     time_started,
-                    last_paused,
-                    total_duration_paused,
                     running_game_stats,
                     game_renderer.as_mut(),
                 ),
@@ -350,6 +561,8 @@ impl<T: Write> App<T> {
                 Menu::ConfigureGame => self.configure_game_menu(),
                 Menu::Quit(string) => break string.clone(),
             }?;
+            // Keys held in the previous menu should not keep repeating in the next one.
+            self.menu_key_held = None;
             // Change screen session depending on what response screen gave.
             match menu_update {
                 MenuUpdate::Pop => {
@@ -379,6 +592,50 @@ impl<T: Write> App<T> {
         )
     }
 
+    /// Reads the next terminal event for menu navigation.
+    ///
+    /// If key release events are available (kitty protocol), holding an arrow key is repeated in
+    /// software according to the menu repeat settings, and OS key repeats are ignored.
+    ///
+    /// Every returned event (including [`Event::Resize`]) causes the calling menu loop to render
+    /// again, which re-centers the menu using [`App::fetch_main_xy`].
+    fn read_menu_event(&mut self) -> io::Result<Event> {
+        if !self.kitty_enabled {
+            return event::read();
+        }
+        loop {
+            if let Some((code, next_repeat)) = self.menu_key_held {
+                let timeout = next_repeat.saturating_duration_since(Instant::now());
+                if !event::poll(timeout)? {
+                    self.menu_key_held =
+                        Some((code, Instant::now() + self.settings.menu_repeat_rate));
+                    return Ok(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+                }
+            }
+            let event = event::read()?;
+            if let Event::Key(KeyEvent { code, kind, .. }) = event {
+                if matches!(
+                    code,
+                    KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right
+                ) {
+                    match kind {
+                        Press => {
+                            self.menu_key_held =
+                                Some((code, Instant::now() + self.settings.menu_repeat_delay));
+                        }
+                        Repeat => continue,
+                        Release => {
+                            if self.menu_key_held.is_some_and(|(held, _)| held == code) {
+                                self.menu_key_held = None;
+                            }
+                        }
+                    }
+                }
+            }
+            break Ok(event);
+        }
+    }
+
     fn generic_placeholder_widget(
         &mut self,
         current_menu_name: &str,
@@ -453,7 +710,7 @@ impl<T: Write> App<T> {
             }
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -475,11 +732,9 @@ impl<T: Write> App<T> {
                     code: KeyCode::Enter,
                     kind: Press,
                     ..
-                }) => {
-                    if !selection.is_empty() {
-                        let menu = selection.into_iter().nth(selected).unwrap();
-                        break Ok(MenuUpdate::Push(menu));
-                    }
+                }) if !selection.is_empty() => {
+                    let menu = selection.into_iter().nth(selected).unwrap();
+                    break Ok(MenuUpdate::Push(menu));
                 }
                 // Move selector up.
                 Event::Key(KeyEvent {
@@ -538,6 +793,10 @@ impl<T: Write> App<T> {
                 GameMode::master(),
                 "challenging - the pieces don't even fly!",
             ),
+            (
+                GameMode::death(),
+                "the pieces barely even touch the ground!",
+            ),
         ];
         let (d_time, d_score, d_pieces, d_lines, d_level) = (Duration::from_secs(5), 200, 10, 5, 1);
         let mut selected = 0usize;
@@ -633,7 +892,7 @@ impl<T: Write> App<T> {
             }
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit app.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -692,6 +951,8 @@ impl<T: Write> App<T> {
                             start_level,
                             increment_level,
                             limits,
+                            objective: None,
+                            recommended_config: None,
                         })
                     } else if selected == selected_cnt - 2 {
                         game_mods::puzzle_mode::make_game()
@@ -702,6 +963,10 @@ impl<T: Write> App<T> {
 
                     // Set config.
                     game.config_mut().clone_from(&self.game_config);
+                    if let Some(patch) = &game.mode().recommended_config {
+                        let patch = patch.clone();
+                        patch.apply(game.config_mut());
+                    }
 
                     // TODO: Remove or make accessible.
                     // unsafe {
@@ -710,13 +975,13 @@ impl<T: Write> App<T> {
                     //     ))
                     // };
 
-                    let now = Instant::now();
+                    let running_game_stats = Rc::new(RefCell::new(RunningGameStats::default()));
+                    game.add_observer(running_game_stats_observer(Rc::clone(&running_game_stats)));
+
                     break Ok(MenuUpdate::Push(Menu::Game {
                         game: Box::new(game),
-                        time_started: now,
-                        last_paused: now,
-                        total_duration_paused: Duration::ZERO,
-                        running_game_stats: RunningGameStats::default(),
+                        time_started: Instant::now(),
+                        running_game_stats,
                         game_renderer: Default::default(),
                     }));
                 }
@@ -814,34 +1079,30 @@ impl<T: Write> App<T> {
                     code: KeyCode::Left,
                     kind: Press | Repeat,
                     ..
-                }) => {
-                    if selected == selected_cnt - 1 && selected_custom > 0 {
-                        selected_custom += selected_custom_cnt - 1
-                    }
+                }) if selected == selected_cnt - 1 && selected_custom > 0 => {
+                    selected_custom += selected_custom_cnt - 1
                 }
                 // Move selector right (select stat).
+                // If custom gamemode selected, allow incrementing stat selection.
                 Event::Key(KeyEvent {
                     code: KeyCode::Right,
                     kind: Press | Repeat,
                     ..
-                }) => {
-                    // If custom gamemode selected, allow incrementing stat selection.
-                    if selected == selected_cnt - 1 {
-                        // If reached last stat, cycle through stats for limit.
-                        if selected_custom == selected_custom_cnt - 1 {
-                            self.custom_mode.mode_limit = match self.custom_mode.mode_limit {
-                                Some(Stat::Time(_)) => Some(Stat::Score(9000)),
-                                Some(Stat::Score(_)) => Some(Stat::Pieces(100)),
-                                Some(Stat::Pieces(_)) => Some(Stat::Lines(40)),
-                                Some(Stat::Lines(_)) => {
-                                    Some(Stat::Level(NonZeroU32::try_from(25).unwrap()))
-                                }
-                                Some(Stat::Level(_)) => None,
-                                None => Some(Stat::Time(Duration::from_secs(120))),
-                            };
-                        } else {
-                            selected_custom += 1
-                        }
+                }) if selected == selected_cnt - 1 => {
+                    // If reached last stat, cycle through stats for limit.
+                    if selected_custom == selected_custom_cnt - 1 {
+                        self.custom_mode.mode_limit = match self.custom_mode.mode_limit {
+                            Some(Stat::Time(_)) => Some(Stat::Score(9000)),
+                            Some(Stat::Score(_)) => Some(Stat::Pieces(100)),
+                            Some(Stat::Pieces(_)) => Some(Stat::Lines(40)),
+                            Some(Stat::Lines(_)) => {
+                                Some(Stat::Level(NonZeroU32::try_from(25).unwrap()))
+                            }
+                            Some(Stat::Level(_)) => None,
+                            None => Some(Stat::Time(Duration::from_secs(120))),
+                        };
+                    } else {
+                        selected_custom += 1
                     }
                 }
                 // Other event: don't care.
@@ -856,19 +1117,30 @@ impl<T: Write> App<T> {
         &mut self,
         game: &mut Game,
         time_started: &mut Instant,
-        last_paused: &mut Instant,
-        total_duration_paused: &mut Duration,
-        running_game_stats: &mut RunningGameStats,
+        running_game_stats: &RefCell<RunningGameStats>,
         game_renderer: &mut impl GameScreenRenderer,
     ) -> io::Result<MenuUpdate> {
         // Prepare channel with which to communicate `Button` inputs / game interrupt.
         let mut buttons_pressed = ButtonsPressed::default();
         let (tx, rx) = mpsc::channel::<ButtonOrSignal>();
-        let _input_handler =
-            CrosstermHandler::new(&tx, &self.settings.keybinds, self.kitty_enabled);
+        let _input_handler = CrosstermHandler::new(
+            &tx,
+            &self.settings.keybinds,
+            self.kitty_enabled,
+            self.settings.pause_on_focus_lost,
+        );
+        let mut board_log = self.board_log.as_ref().and_then(|path| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .ok()
+        });
         // Game Loop
         let session_resumed = Instant::now();
-        *total_duration_paused += session_resumed.saturating_duration_since(*last_paused);
+        // Time passed since the game started, including pauses (the game itself discounts those).
+        let mut real_time_now = session_resumed.saturating_duration_since(*time_started);
+        game.resume(real_time_now);
         let mut clean_screen = true;
         let mut f = 0u32;
         let mut fps_counter = 0;
@@ -876,7 +1148,7 @@ impl<T: Write> App<T> {
         let menu_update = 'render_loop: loop {
             // Exit if game ended
             if game.ended() {
-                let finished_game_stats = self.store_game(game, running_game_stats);
+                let finished_game_stats = self.store_game(game, &running_game_stats.borrow());
                 let menu = if finished_game_stats.was_successful() {
                     Menu::GameComplete
                 } else {
@@ -901,20 +1173,21 @@ impl<T: Write> App<T> {
                 let frame_idle_remaining = next_frame_at - Instant::now();
                 match rx.recv_timeout(frame_idle_remaining) {
                     Ok(Err(Signal::ExitProgram)) => {
-                        self.store_game(game, running_game_stats);
+                        self.store_game(game, &running_game_stats.borrow());
                         break 'render_loop MenuUpdate::Push(Menu::Quit(
                             "exited with ctrl-c".to_string(),
                         ));
                     }
                     Ok(Err(Signal::ForfeitGame)) => {
                         game.forfeit();
-                        let finished_game_stats = self.store_game(game, running_game_stats);
+                        let finished_game_stats =
+                            self.store_game(game, &running_game_stats.borrow());
                         break 'render_loop MenuUpdate::Push(Menu::GameOver(Box::new(
                             finished_game_stats,
                         )));
                     }
                     Ok(Err(Signal::Pause)) => {
-                        *last_paused = Instant::now();
+                        game.pause(Instant::now().saturating_duration_since(*time_started));
                         break 'render_loop MenuUpdate::Push(Menu::Pause);
                     }
                     Ok(Err(Signal::WindowResize)) => {
@@ -923,36 +1196,38 @@ impl<T: Write> App<T> {
                     }
                     Ok(Ok((instant, button, button_state))) => {
                         buttons_pressed[button] = button_state;
-                        let game_time_userinput = instant.saturating_duration_since(*time_started)
-                            - *total_duration_paused;
-                        let game_now = std::cmp::max(game_time_userinput, game.state().time);
+                        let real_time_userinput = instant.saturating_duration_since(*time_started);
+                        real_time_now = std::cmp::max(real_time_userinput, real_time_now);
                         // TODO: Handle/ensure no Err.
-                        if let Ok(evts) = game.update(Some(buttons_pressed), game_now) {
+                        if let Ok(evts) = game.update(Some(buttons_pressed), real_time_now) {
                             new_feedback_events.extend(evts);
                         }
                     }
                     Err(mpsc::RecvTimeoutError::Timeout) => {
-                        let game_time_now = Instant::now().saturating_duration_since(*time_started)
-                            - *total_duration_paused;
+                        real_time_now = Instant::now().saturating_duration_since(*time_started);
                         // TODO: Handle/ensure no Err.
-                        if let Ok(evts) = game.update(None, game_time_now) {
+                        if let Ok(evts) = game.update(None, real_time_now) {
                             new_feedback_events.extend(evts);
                         }
                         break 'idle_loop;
                     }
                     Err(mpsc::RecvTimeoutError::Disconnected) => {
                         // NOTE: We kind of rely on this not happening too often.
+                        game.pause(Instant::now().saturating_duration_since(*time_started));
                         break 'render_loop MenuUpdate::Push(Menu::Pause);
                     }
                 };
             }
-            game_renderer.render(
-                self,
-                game,
-                running_game_stats,
-                new_feedback_events,
-                clean_screen,
-            )?;
+            if let Some(board_log) = &mut board_log {
+                let piece_locked = new_feedback_events
+                    .iter()
+                    .any(|(_, feedback)| matches!(feedback, Feedback::PieceLocked(_)));
+                if piece_locked {
+                    // The log is only a debugging aid, failing to write it must not end the game.
+                    let _ = board_log.write_all(plaintext::board_snapshot(game).as_bytes());
+                }
+            }
+            game_renderer.render(self, game, new_feedback_events, clean_screen)?;
             clean_screen = false;
             // FPS counter.
             if self.settings.show_fps {
@@ -1014,6 +1289,16 @@ impl<T: Write> App<T> {
             score,
             consecutive_line_clears: _,
             back_to_back_special_clears: _,
+            perfect_clears: _,
+            time_bonus: _,
+            input_counts: _,
+            spawns_since_seen: _,
+            max_stack_height,
+            queued_initial_rotation: _,
+            queued_garbage: _,
+            piece_inputs: _,
+            finesse_faults: _,
+            das_charged: _,
         } = last_state;
         let actions_str = [
             format!(
@@ -1089,6 +1374,11 @@ impl<T: Write> App<T> {
                     "{:^w_main$}",
                     format!("Time: {}", format_duration(*game_time))
                 )))?
+                .queue(MoveTo(x_main, y_main + y_selection + 9))?
+                .queue(Print(format!(
+                    "{:^w_main$}",
+                    format!("Max. stack height: {max_stack_height}")
+                )))?
                 .queue(MoveTo(x_main, y_main + y_selection + 10))?
                 .queue(Print(format!("{:^w_main$}", actions_str)))?
                 .queue(MoveTo(x_main, y_main + y_selection + 11))?
@@ -1123,7 +1413,7 @@ impl<T: Write> App<T> {
             }
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -1145,31 +1435,25 @@ impl<T: Write> App<T> {
                     code: KeyCode::Enter,
                     kind: Press,
                     ..
-                }) => {
-                    if !selection.is_empty() {
-                        let menu = selection.into_iter().nth(selected).unwrap();
-                        break Ok(MenuUpdate::Push(menu));
-                    }
+                }) if !selection.is_empty() => {
+                    let menu = selection.into_iter().nth(selected).unwrap();
+                    break Ok(MenuUpdate::Push(menu));
                 }
                 // Move selector up.
                 Event::Key(KeyEvent {
                     code: KeyCode::Up,
                     kind: Press | Repeat,
                     ..
-                }) => {
-                    if !selection.is_empty() {
-                        selected += selection.len() - 1;
-                    }
+                }) if !selection.is_empty() => {
+                    selected += selection.len() - 1;
                 }
                 // Move selector down.
                 Event::Key(KeyEvent {
                     code: KeyCode::Down,
                     kind: Press | Repeat,
                     ..
-                }) => {
-                    if !selection.is_empty() {
-                        selected += 1;
-                    }
+                }) if !selection.is_empty() => {
+                    selected += 1;
                 }
                 // Other event: don't care.
                 _ => {}
@@ -1218,7 +1502,7 @@ impl<T: Write> App<T> {
     }
 
     fn settings_menu(&mut self) -> io::Result<MenuUpdate> {
-        let selection_len = 7;
+        let selection_len = 13;
         let mut selected = 0usize;
         loop {
             let w_main = Self::W_MAIN.into();
@@ -1233,10 +1517,28 @@ impl<T: Write> App<T> {
             let labels = [
                 "| Change Controls .. |".to_string(),
                 "| Configure Game .. |".to_string(),
+                match self.settings.theme {
+                    Some(theme) => format!("theme : '{theme:?}'"),
+                    None => "theme : (custom)".to_string(),
+                },
                 format!("graphics : '{:?}'", self.settings.graphics_style),
                 format!("color : '{:?}'", self.settings.graphics_color),
+                format!("palette : '{:?}'", self.settings.palette),
                 format!("framerate : {}", self.settings.game_fps),
                 format!("show fps : {}", self.settings.show_fps),
+                format!(
+                    "menu key repeat delay : {}ms",
+                    self.settings.menu_repeat_delay.as_millis()
+                ),
+                format!(
+                    "menu key repeat rate : {}ms",
+                    self.settings.menu_repeat_rate.as_millis()
+                ),
+                format!("beginner assist : {}", self.settings.beginner_assist),
+                format!(
+                    "pause on focus lost : {}",
+                    self.settings.pause_on_focus_lost
+                ),
                 if self.settings.save_data_on_exit {
                     "Keep savefile for tetrs : On"
                 } else {
@@ -1269,7 +1571,7 @@ impl<T: Write> App<T> {
                 ))?;
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -1318,26 +1620,54 @@ impl<T: Write> App<T> {
                     ..
                 }) => match selected {
                     2 => {
+                        let next = match self.settings.theme {
+                            Some(theme) => Theme::ALL[(theme as usize + 1) % Theme::ALL.len()],
+                            None => Theme::ALL[0],
+                        };
+                        next.apply(&mut self.settings, &mut self.game_config);
+                    }
+                    3 => {
+                        self.settings.theme = None;
                         self.settings.graphics_style = match self.settings.graphics_style {
                             GraphicsStyle::Electronika60 => GraphicsStyle::ASCII,
                             GraphicsStyle::ASCII => GraphicsStyle::Unicode,
                             GraphicsStyle::Unicode => GraphicsStyle::Electronika60,
                         };
                     }
-                    3 => {
+                    4 => {
+                        self.settings.theme = None;
                         self.settings.graphics_color = match self.settings.graphics_color {
                             GraphicsColor::Monochrome => GraphicsColor::Color16,
                             GraphicsColor::Color16 => GraphicsColor::ColorRGB,
                             GraphicsColor::ColorRGB => GraphicsColor::Monochrome,
                         };
                     }
-                    4 => {
+                    5 => {
+                        self.settings.theme = None;
+                        self.settings.palette = match self.settings.palette {
+                            Palette::Standard => Palette::Colorblind,
+                            Palette::Colorblind => Palette::Standard,
+                        };
+                    }
+                    6 => {
                         self.settings.game_fps += 1.0;
                     }
-                    5 => {
+                    7 => {
                         self.settings.show_fps = !self.settings.show_fps;
                     }
-                    6 => {
+                    8 => {
+                        self.settings.menu_repeat_delay += Duration::from_millis(10);
+                    }
+                    9 => {
+                        self.settings.menu_repeat_rate += Duration::from_millis(10);
+                    }
+                    10 => {
+                        self.settings.beginner_assist = !self.settings.beginner_assist;
+                    }
+                    11 => {
+                        self.settings.pause_on_focus_lost = !self.settings.pause_on_focus_lost;
+                    }
+                    12 => {
                         self.settings.save_data_on_exit = !self.settings.save_data_on_exit;
                     }
                     _ => {}
@@ -1348,28 +1678,60 @@ impl<T: Write> App<T> {
                     ..
                 }) => match selected {
                     2 => {
+                        let next = match self.settings.theme {
+                            Some(theme) => {
+                                Theme::ALL
+                                    [(theme as usize + Theme::ALL.len() - 1) % Theme::ALL.len()]
+                            }
+                            None => Theme::ALL[Theme::ALL.len() - 1],
+                        };
+                        next.apply(&mut self.settings, &mut self.game_config);
+                    }
+                    3 => {
+                        self.settings.theme = None;
                         self.settings.graphics_style = match self.settings.graphics_style {
                             GraphicsStyle::Electronika60 => GraphicsStyle::Unicode,
                             GraphicsStyle::ASCII => GraphicsStyle::Electronika60,
                             GraphicsStyle::Unicode => GraphicsStyle::ASCII,
                         };
                     }
-                    3 => {
+                    4 => {
+                        self.settings.theme = None;
                         self.settings.graphics_color = match self.settings.graphics_color {
                             GraphicsColor::Monochrome => GraphicsColor::ColorRGB,
                             GraphicsColor::Color16 => GraphicsColor::Monochrome,
                             GraphicsColor::ColorRGB => GraphicsColor::Color16,
                         };
                     }
-                    4 => {
-                        if self.settings.game_fps >= 1.0 {
-                            self.settings.game_fps -= 1.0;
-                        }
-                    }
                     5 => {
+                        self.settings.theme = None;
+                        self.settings.palette = match self.settings.palette {
+                            Palette::Standard => Palette::Colorblind,
+                            Palette::Colorblind => Palette::Standard,
+                        };
+                    }
+                    6 if self.settings.game_fps >= 1.0 => {
+                        self.settings.game_fps -= 1.0;
+                    }
+                    7 => {
                         self.settings.show_fps = !self.settings.show_fps;
                     }
-                    6 => {
+                    8 => {
+                        self.settings.menu_repeat_delay = self
+                            .settings
+                            .menu_repeat_delay
+                            .saturating_sub(Duration::from_millis(10));
+                    }
+                    9 if self.settings.menu_repeat_rate > Duration::from_millis(10) => {
+                        self.settings.menu_repeat_rate -= Duration::from_millis(10);
+                    }
+                    10 => {
+                        self.settings.beginner_assist = !self.settings.beginner_assist;
+                    }
+                    11 => {
+                        self.settings.pause_on_focus_lost = !self.settings.pause_on_focus_lost;
+                    }
+                    12 => {
                         self.settings.save_data_on_exit = !self.settings.save_data_on_exit;
                     }
                     _ => {}
@@ -1455,7 +1817,7 @@ impl<T: Write> App<T> {
                 ))?;
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -1499,12 +1861,16 @@ impl<T: Write> App<T> {
                                 .italic(),
                             ))?;
                         loop {
-                            if let Event::Key(KeyEvent {
-                                code, kind: Press, ..
-                            }) = event::read()?
-                            {
-                                self.settings.keybinds.insert(code, current_button);
-                                break;
+                            match event::read()? {
+                                Event::Key(KeyEvent {
+                                    code, kind: Press, ..
+                                }) => {
+                                    self.settings.keybinds.insert(code, current_button);
+                                    break;
+                                }
+                                // Abort so the menu can reflow to the new terminal size.
+                                Event::Resize(..) => break,
+                                _ => {}
                             }
                         }
                     }
@@ -1533,7 +1899,7 @@ impl<T: Write> App<T> {
     }
 
     fn configure_game_menu(&mut self) -> io::Result<MenuUpdate> {
-        let selection_len = 12;
+        let selection_len = 13;
         let mut selected = 0usize;
         loop {
             let w_main = Self::W_MAIN.into();
@@ -1557,6 +1923,8 @@ impl<T: Write> App<T> {
                         TetrominoGenerator::Bag { .. } => "7-Bag",
                         TetrominoGenerator::Recency { .. } => "Recency/History",
                         TetrominoGenerator::TotalRelative { .. } => "Total Relative Counts",
+                        TetrominoGenerator::Nes { .. } => "NES",
+                        TetrominoGenerator::Constant { .. } => "Constant",
                     }
                 ),
                 format!("preview count : {}", self.game_config.preview_count),
@@ -1577,6 +1945,7 @@ impl<T: Write> App<T> {
                     "*no soft drop lock : {}",
                     self.game_config.no_soft_drop_lock
                 ),
+                format!("show ghost piece : {}", self.game_config.show_ghost),
             ];
             for (i, label) in labels.into_iter().enumerate() {
                 self.term
@@ -1630,7 +1999,7 @@ impl<T: Write> App<T> {
                 )))?;
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -1652,11 +2021,9 @@ impl<T: Write> App<T> {
                     code: KeyCode::Enter,
                     kind: Press,
                     ..
-                }) => {
-                    if selected == selection_len - 1 {
-                        self.game_config = GameConfig::default();
-                        self.game_config.no_soft_drop_lock = !self.kitty_enabled;
-                    }
+                }) if selected == selection_len - 1 => {
+                    self.game_config = GameConfig::default();
+                    self.game_config.no_soft_drop_lock = !self.kitty_enabled;
                 }
                 // Move selector up.
                 Event::Key(KeyEvent {
@@ -1691,14 +2058,14 @@ impl<T: Write> App<T> {
                             .game_config
                             .tetromino_generator
                         {
-                            TetrominoGenerator::Uniform => TetrominoGenerator::bag(NonZeroU32::MIN),
+                            TetrominoGenerator::Uniform => TetrominoGenerator::seven_bag(),
                             TetrominoGenerator::Bag { .. } => TetrominoGenerator::recency(),
                             TetrominoGenerator::Recency { .. } => {
                                 TetrominoGenerator::total_relative()
                             }
-                            TetrominoGenerator::TotalRelative { .. } => {
-                                TetrominoGenerator::uniform()
-                            }
+                            TetrominoGenerator::TotalRelative { .. } => TetrominoGenerator::nes(),
+                            TetrominoGenerator::Nes { .. }
+                            | TetrominoGenerator::Constant { .. } => TetrominoGenerator::uniform(),
                         };
                     }
                     2 => {
@@ -1728,6 +2095,10 @@ impl<T: Write> App<T> {
                     10 => {
                         self.game_config.no_soft_drop_lock = !self.game_config.no_soft_drop_lock;
                     }
+                    11 => {
+                        self.game_config.show_ghost = !self.game_config.show_ghost;
+                        self.settings.theme = None;
+                    }
                     _ => {}
                 },
                 Event::Key(KeyEvent {
@@ -1743,17 +2114,19 @@ impl<T: Write> App<T> {
                         };
                     }
                     1 => {
-                        self.game_config.tetromino_generator =
-                            match self.game_config.tetromino_generator {
-                                TetrominoGenerator::Uniform => TetrominoGenerator::total_relative(),
-                                TetrominoGenerator::Bag { .. } => TetrominoGenerator::uniform(),
-                                TetrominoGenerator::Recency { .. } => {
-                                    TetrominoGenerator::bag(NonZeroU32::MIN)
-                                }
-                                TetrominoGenerator::TotalRelative { .. } => {
-                                    TetrominoGenerator::recency()
-                                }
-                            };
+                        self.game_config.tetromino_generator = match self
+                            .game_config
+                            .tetromino_generator
+                        {
+                            TetrominoGenerator::Uniform => TetrominoGenerator::nes(),
+                            TetrominoGenerator::Bag { .. } => TetrominoGenerator::uniform(),
+                            TetrominoGenerator::Recency { .. } => TetrominoGenerator::seven_bag(),
+                            TetrominoGenerator::TotalRelative { .. } => {
+                                TetrominoGenerator::recency()
+                            }
+                            TetrominoGenerator::Nes { .. } => TetrominoGenerator::total_relative(),
+                            TetrominoGenerator::Constant { .. } => TetrominoGenerator::nes(),
+                        };
                     }
                     2 => {
                         self.game_config.preview_count =
@@ -1772,17 +2145,15 @@ impl<T: Write> App<T> {
                             .saturating_sub(Duration::from_millis(1));
                     }
                     5 => {
-                        if self.game_config.soft_drop_factor > 0.0 {
-                            self.game_config.soft_drop_factor -= 0.25;
-                        }
+                        // NOTE: A factor below 1 would make soft drop slower than gravity.
+                        self.game_config.soft_drop_factor =
+                            (self.game_config.soft_drop_factor - 0.25).max(1.0);
                     }
-                    6 => {
-                        if self.game_config.hard_drop_delay >= Duration::from_millis(1) {
-                            self.game_config.hard_drop_delay = self
-                                .game_config
-                                .hard_drop_delay
-                                .saturating_sub(Duration::from_millis(1));
-                        }
+                    6 if self.game_config.hard_drop_delay >= Duration::from_millis(1) => {
+                        self.game_config.hard_drop_delay = self
+                            .game_config
+                            .hard_drop_delay
+                            .saturating_sub(Duration::from_millis(1));
                     }
                     7 => {
                         self.game_config.ground_time_max = self
@@ -1805,6 +2176,10 @@ impl<T: Write> App<T> {
                     10 => {
                         self.game_config.no_soft_drop_lock = !self.game_config.no_soft_drop_lock;
                     }
+                    11 => {
+                        self.game_config.show_ghost = !self.game_config.show_ghost;
+                        self.settings.theme = None;
+                    }
                     _ => {}
                 },
                 // Other event: don't care.
@@ -1817,6 +2192,7 @@ impl<T: Write> App<T> {
     fn scores_menu(&mut self) -> io::Result<MenuUpdate> {
         let max_entries = 16;
         let mut scroll = 0usize;
+        let mut export_status: Option<String> = None;
         loop {
             let w_main = Self::W_MAIN.into();
             let (x_main, y_main) = Self::fetch_main_xy();
@@ -1899,7 +2275,7 @@ impl<T: Write> App<T> {
                                     },
                                 )
                             }
-                            "Master" => {
+                            "Master" | "Death" => {
                                 let Limits {
                                     lines: Some((_, max_lns)),
                                     ..
@@ -1908,8 +2284,8 @@ impl<T: Write> App<T> {
                                     panic!()
                                 };
                                 format!(
-                                    "{timestamp} ~ Master: {}/{} lns",
-                                    last_state.lines_cleared, max_lns
+                                    "{timestamp} ~ {}: {}/{} lns",
+                                    gamemode.name, last_state.lines_cleared, max_lns
                                 )
                             }
                             "Puzzle" => {
@@ -1936,33 +2312,12 @@ impl<T: Write> App<T> {
                                     last_state.lines_cleared,
                                     last_state.score,
                                     format_duration(last_state.time),
-                                    [
-                                        gamemode.limits.time.map(|(_, max_dur)| format!(
-                                            " ({} / {})",
-                                            format_duration(last_state.time),
-                                            format_duration(max_dur)
-                                        )),
-                                        gamemode.limits.pieces.map(|(_, max_pcs)| format!(
-                                            " ({}/{} pcs)",
-                                            last_state.pieces_played.iter().sum::<u32>(),
-                                            max_pcs
-                                        )),
-                                        gamemode.limits.lines.map(|(_, max_lns)| format!(
-                                            " ({}/{} lns)",
-                                            last_state.lines_cleared, max_lns
-                                        )),
-                                        gamemode.limits.level.map(|(_, max_lvl)| format!(
-                                            " ({}/{} lvl)",
-                                            last_state.level, max_lvl
-                                        )),
-                                        gamemode.limits.score.map(|(_, max_pts)| format!(
-                                            " ({}/{} pts)",
-                                            last_state.score, max_pts
-                                        )),
-                                    ]
-                                    .into_iter()
-                                    .find_map(|limit_text| limit_text)
-                                    .unwrap_or_default()
+                                    gamemode
+                                        .limits
+                                        .describe_progress(last_state)
+                                        .first()
+                                        .map(|progress| format!(" ({progress})"))
+                                        .unwrap_or_default()
                                 )
                             }
                         }
@@ -1990,9 +2345,23 @@ impl<T: Write> App<T> {
                         format!("...  (+{entries_left} more)")
                     )))?;
             }
+            self.term
+                .queue(MoveTo(
+                    x_main,
+                    y_main + y_selection + 4 + u16::try_from(max_entries).unwrap() + 2,
+                ))?
+                .queue(PrintStyledContent(
+                    format!(
+                        "{:^w_main$}",
+                        export_status
+                            .as_deref()
+                            .unwrap_or("Use [↑] [↓] [Esc], [E] to export as CSV.")
+                    )
+                    .italic(),
+                ))?;
             self.term.flush()?;
             // Wait for new input.
-            match event::read()? {
+            match self.read_menu_event()? {
                 // Quit menu.
                 Event::Key(KeyEvent {
                     code: KeyCode::Char('c'),
@@ -2022,10 +2391,20 @@ impl<T: Write> App<T> {
                     code: KeyCode::Down,
                     kind: Press | Repeat,
                     ..
+                }) if entries_left > 0 => {
+                    scroll += 1;
+                }
+                // Export scores.
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('e'),
+                    kind: Press,
+                    ..
                 }) => {
-                    if entries_left > 0 {
-                        scroll += 1;
-                    }
+                    let export_path = Self::savefile_path().with_file_name(Self::EXPORT_NAME);
+                    export_status = Some(match self.export_csv(&export_path) {
+                        Ok(()) => format!("Exported to {}", export_path.display()),
+                        Err(e) => format!("Could not export: {e}"),
+                    });
                 }
                 // Other event: don't care.
                 _ => {}
@@ -2044,7 +2423,7 @@ impl<T: Write> App<T> {
     fn store_game(
         &mut self,
         game: &Game,
-        running_game_stats: &mut RunningGameStats,
+        running_game_stats: &RunningGameStats,
     ) -> FinishedGameStats {
         let finished_game_stats = FinishedGameStats {
             timestamp: chrono::Utc::now().format("%Y-%m-%d %H:%M").to_string(),
@@ -2054,66 +2433,54 @@ impl<T: Write> App<T> {
             last_state: game.state().clone(),
         };
         self.past_games.push(finished_game_stats.clone());
-        self.past_games
-            .sort_by(|stats1, stats2| {
-                // First sort by gamemode.
-                stats1.gamemode.name.cmp(&stats2.gamemode.name).then_with(|| {
-                    // Sort by whether game was finished successfully or not.
-                    let end1 = stats1.last_state.end.is_some_and(|end| end.is_ok());
-                    let end2 = stats2.last_state.end.is_some_and(|end| end.is_ok());
-                    end1.cmp(&end2).reverse().then_with(|| {
-                        // Depending on gamemode, sort differently.
-                        match stats1.gamemode.name.as_str() {
-                            "Marathon" => {
-                                // Sort desc by level.
-                                stats1.last_state.level.cmp(&stats2.last_state.level).reverse().then_with(||
-                                    // Sort desc by score.
-
-                                    stats1.last_state.score.cmp(&stats2.last_state.score).reverse()
-                                )
-                            },
-                            "40-Lines" => {
-                                // Sort desc by lines.
-                                stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse().then_with(||
-                                    // Sort asc by time.
-                                    stats1.last_state.time.cmp(&stats2.last_state.time)
-                                )
-                            },
-                            "Time Trial" => {
-                                // Sort asc by time.
-                                stats1.last_state.time.cmp(&stats2.last_state.time).then_with(||
-                                    // Sort by desc lines.
-                                    stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse()
-                                )
-                            },
-                            "Master" => {
-                                // Sort desc by lines.
-                                stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse()
-                            },
-                            "Puzzle" => {
-                                // Sort desc by level.
-                                stats1.last_state.level.cmp(&stats2.last_state.level).reverse().then_with(||
-                                    // Sort asc by time.
-                                    stats1.last_state.time.cmp(&stats2.last_state.time)
-                                )
-                            },
-                            _ => {
-                                // Sort desc by lines.
-                                stats1.last_state.lines_cleared.cmp(&stats2.last_state.lines_cleared).reverse()
-                            },
-                        }.then_with(|| {
-                            // Sort asc by timestamp.
-                            stats1.timestamp.cmp(&stats2.timestamp)
-                        })
-                    })
-                })
-            });
+        self.past_games.sort_by(FinishedGameStats::scoreboard_cmp);
         finished_game_stats
     }
 
     const DAVIS: &'static str = " ▀█▀ \"I am like Solomon because I built God's temple, an operating system. God said 640x480 16 color graphics but the operating system is 64-bit and multi-cored! Go draw a 16 color elephant. Then, draw a 24-bit elephant in MS Paint and be enlightened. Artist stopped photorealism when the camera was invented. A cartoon is actually better than photorealistic. For the next thousand years, first-person shooters are going to get boring. Tetris looks good.\" - In memory of Terry A. Davis";
 }
 
+/// Writes the given past games as CSV, with a header row followed by one row per game.
+pub fn write_csv(past_games: &[FinishedGameStats], path: &Path) -> io::Result<()> {
+    let mut file = io::BufWriter::new(File::create(path)?);
+    writeln!(
+        file,
+        "timestamp,mode,finished,score,level,lines,time_secs,pieces,spins,singles,doubles,triples,quadruples"
+    )?;
+    for FinishedGameStats {
+        timestamp,
+        actions,
+        score_bonuses: _,
+        gamemode,
+        last_state,
+    } in past_games
+    {
+        let [spins, singles, doubles, triples, quadruples] = actions;
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{:.3},{},{spins},{singles},{doubles},{triples},{quadruples}",
+            csv_field(timestamp),
+            csv_field(&gamemode.name),
+            last_state.end.is_some_and(|end| end.is_ok()),
+            last_state.score,
+            last_state.level,
+            last_state.lines_cleared,
+            last_state.time.as_secs_f64(),
+            last_state.pieces_played.iter().sum::<u32>(),
+        )?;
+    }
+    file.flush()
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or line break.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
 pub fn format_duration(dur: Duration) -> String {
     format!(
         "{}min {}.{:02}sec",
@@ -2154,3 +2521,138 @@ pub fn format_keybinds(button: Button, keybinds: &HashMap<KeyCode, Button>) -> S
         .collect::<Vec<String>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tetrs_engine::Tetromino;
+
+    fn finished_game() -> FinishedGameStats {
+        let mut game = Game::new(GameMode::marathon());
+        let mut buttons = ButtonsPressed::default();
+        buttons[Button::DropHard] = true;
+        let _ = game.update(Some(buttons), Duration::from_secs(2));
+        game.forfeit();
+        FinishedGameStats {
+            timestamp: "2024-08-01 12:00".to_string(),
+            actions: [0, 1, 0, 0, 0],
+            score_bonuses: vec![100],
+            gamemode: game.mode().clone(),
+            last_state: game.state().clone(),
+        }
+    }
+
+    fn past_game(mode: &str, level: u32, score: u32, secs: u64) -> FinishedGameStats {
+        let mut stats = finished_game();
+        stats.gamemode.name = mode.to_string();
+        stats.last_state.level = NonZeroU32::new(level).unwrap();
+        stats.last_state.score = score;
+        stats.last_state.time = Duration::from_secs(secs);
+        stats
+    }
+
+    #[test]
+    fn scoreboard_keeps_mode_tie_breakers() {
+        let mut past_games = [
+            past_game("Marathon", 3, 9000, 60),
+            past_game("Marathon", 5, 1000, 90),
+            past_game("Marathon", 5, 2000, 120),
+            past_game("Puzzle", 10, 0, 300),
+            past_game("Puzzle", 10, 0, 200),
+            past_game("Puzzle", 12, 0, 400),
+        ];
+        past_games.sort_by(FinishedGameStats::scoreboard_cmp);
+        let order = past_games
+            .iter()
+            .map(|stats| {
+                (
+                    stats.gamemode.name.as_str(),
+                    stats.last_state.level.get(),
+                    stats.last_state.score,
+                    stats.last_state.time.as_secs(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            [
+                ("Marathon", 5, 2000, 120),
+                ("Marathon", 5, 1000, 90),
+                ("Marathon", 3, 9000, 60),
+                ("Puzzle", 12, 0, 400),
+                ("Puzzle", 10, 0, 200),
+                ("Puzzle", 10, 0, 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn board_snapshot_is_plain_text() {
+        let config = GameConfig {
+            tetromino_generator: TetrominoGenerator::constant(Tetromino::O),
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config_and_seed(GameMode::zen(), config, 0);
+        let mut time = Duration::ZERO;
+        while game.state().active_piece_data.is_none() {
+            time += Duration::from_millis(10);
+            game.update(None, time).unwrap();
+        }
+        let mut buttons = ButtonsPressed::default();
+        buttons[Button::DropHard] = true;
+        time += Duration::from_millis(10);
+        game.update(Some(buttons), time).unwrap();
+        while game.state().pieces_played[Tetromino::O] == 0 {
+            time += Duration::from_millis(10);
+            game.update(Some(ButtonsPressed::default()), time).unwrap();
+        }
+        let snapshot = plaintext::board_snapshot(&game);
+        assert!(!snapshot.contains('\x1b'));
+        let bottom_line = snapshot.lines().find(|line| line.starts_with("00 |"));
+        assert!(bottom_line.is_some_and(|line| line.contains("OOOO")));
+    }
+
+    #[test]
+    fn savefile_round_trips() {
+        let settings = Settings {
+            save_data_on_exit: true,
+            ..Settings::default()
+        };
+        let custom_mode = CustomModeStore {
+            name: "Custom Mode".to_string(),
+            start_level: NonZeroU32::MIN,
+            increment_level: true,
+            mode_limit: Some(Stat::Lines(40)),
+        };
+        let save_data: SaveData = (
+            settings,
+            custom_mode,
+            GameConfig::default(),
+            vec![finished_game()],
+        );
+        let save_str = serde_json::to_string(&save_data).unwrap();
+        // The board keeps its plain representation, as in savefiles of earlier versions.
+        let save_value: serde_json::Value = serde_json::from_str(&save_str).unwrap();
+        assert!(save_value[3][0]["last_state"]["board"][0].is_array());
+        let loaded: SaveData = serde_json::from_str(&save_str).unwrap();
+        assert_eq!(serde_json::to_value(&loaded).unwrap(), save_value);
+    }
+    #[test]
+    fn loads_savefile_of_earlier_version() {
+        let save_str = include_str!("../tests/data/baseline_savefile.json");
+        let (settings, custom_mode, game_config, past_games) =
+            serde_json::from_str::<SaveData>(save_str).unwrap();
+        assert!(settings.save_data_on_exit);
+        assert_eq!(settings.menu_repeat_delay, Duration::from_millis(200));
+        assert!(settings.pause_on_focus_lost);
+        assert_eq!(custom_mode.mode_limit, Some(Stat::Lines(40)));
+        assert_eq!(game_config.skyline, Game::SKYLINE);
+        assert_eq!(past_games.len(), 2);
+        assert_eq!(past_games[0].gamemode.name, "Marathon");
+        assert_eq!(past_games[0].last_state.board.len(), Game::HEIGHT);
+        assert_eq!(
+            past_games[1].last_state.end,
+            Some(Err(tetrs_engine::GameOver::Forfeit))
+        );
+    }
+}